@@ -20,57 +20,110 @@
 //!     assert!(data[..] == my_data[..]);
 //! }
 //! ```
+//!
+//! The encoding works on pairs of bytes. For a pair `[a, b]`, the word is the entry at index
+//! `a * 4 + b / 64` of the seed15 dictionary and the suffix is `b % 64`. A trailing odd byte `a` is
+//! encoded as the word at index `a` followed by the suffix 64. Words are written in the same order
+//! as the bytes and are separated by single spaces. The dictionary is the one published in the
+//! dictionary-1024 crate, and only the first 3 letters of each word are needed to identify it.
+//!
+//! ```
+//! use mnemonic_16bit::binary_to_phrase;
+//!
+//! assert!(binary_to_phrase(&[0, 63]) == "abbey63");
+//! assert!(binary_to_phrase(&[0, 64]) == "able0");
+//! assert!(binary_to_phrase(&[255, 255]) == "yank63");
+//! assert!(binary_to_phrase(&[5]) == "ace64");
+//! ```
+//!
+//! Optional integrations are available behind cargo features:
+//!
+//! - `base64`: convert a `Phrase` to and from Base64.
+//! - `cache`: decode phrases through a per-thread LRU cache.
+//! - `fast_fmt`: format numerical suffixes with itoa when encoding phrases.
+//! - `hmac`: tag phrases with a 1 byte HMAC-SHA256 tag.
+//! - `log`: log decoding failures instead of returning them.
+//! - `serde_json`: encode and decode phrases wrapped in JSON objects.
+//! - `toml`: encode and decode phrases as TOML key-value lines.
+//! - `tracing`: encode and decode phrases inside tracing spans.
+//! - `zeroize`: wipe phrase strings from memory after decoding them.
 
 use anyhow::{bail, Context, Error, Result};
-use dictionary_1024::{word_at_index, index_of_word};
+use dictionary_1024::{word_at_index, index_of_word, DICTIONARY, DICTIONARY_UNIQUE_PREFIX};
+use std::fmt;
+use std::str::FromStr;
 
 /// binary_to_phrase will convert a binary string to a phrase.
+///
+/// ```
+/// use mnemonic_16bit::binary_to_phrase;
+///
+/// assert!(binary_to_phrase(&[0, 0]) == "abbey0");
+/// assert!(binary_to_phrase(&[0, 0, 5]) == "abbey0 ace64");
+/// ```
 pub fn binary_to_phrase(data: &[u8]) -> String {
-    // Base case, no data means no mnemonic.
-    let mut phrase = "".to_string();
-    if data.len() == 0 {
-        return phrase;
-    }
-
-    // Parse out all of the even-numbered bytes.
-    let mut i = 0;
-    while i+1 < data.len() {
-        // Determine the dictionary offset.
-        let mut word_index = data[i] as u16;
-        word_index *= 4;
-        let word_bits = data[i+1] / 64;
-        word_index += word_bits as u16;
-        let word = word_at_index(word_index as usize);
+    encode_bytes(data.iter().copied())
+}
 
-        // Determine the accompanying number.
-        let num = data[i+1] % 64;
+/// word_indices will convert a stream of bytes into the dictionary index and numerical suffix of
+/// each word in its phrase. The suffix is 64 for a final word that only holds one byte.
+fn word_indices(bytes: impl IntoIterator<Item = u8>) -> impl Iterator<Item = (usize, u8)> {
+    let mut bytes = bytes.into_iter();
+    std::iter::from_fn(move || {
+        let first = bytes.next()?;
+        match bytes.next() {
+            // The first byte and the top 2 bits of the second byte determine the dictionary
+            // offset, the remaining 6 bits become the numerical suffix.
+            Some(second) => Some((first as usize * 4 + (second / 64) as usize, second % 64)),
+            // A trailing odd byte is represented by the word alone.
+            None => Some((first as usize, 64)),
+        }
+    })
+}
 
+/// encode_bytes will convert a stream of bytes into a phrase. It backs binary_to_phrase and
+/// allows callers to encode several byte slices as one phrase without concatenating them first.
+fn encode_bytes(bytes: impl IntoIterator<Item = u8>) -> String {
+    let mut phrase = String::new();
+    for (word_index, num) in word_indices(bytes) {
         // Compose the word into the phrase.
-        if phrase.len() != 0 {
+        if !phrase.is_empty() {
             phrase += " ";
         }
-        phrase += &word;
-        phrase += &format!("{}", num);
-        i += 2;
+        phrase.push_str(DICTIONARY[word_index]);
+        push_suffix(&mut phrase, num);
     }
+    phrase
+}
 
-    // Parse out the final word.
-    if data.len() % 2 == 1 {
-        let word = word_at_index(data[i] as usize);
-        if phrase.len() != 0 {
-            phrase += " ";
-        }
-        phrase += &word;
-        phrase += "64";
-    }
+/// push_suffix will append the numerical suffix of a word to the phrase without allocating a
+/// temporary string for the number.
+#[cfg(not(feature = "fast_fmt"))]
+fn push_suffix(phrase: &mut String, num: u8) {
+    use std::fmt::Write;
+    // Writing to a String cannot fail.
+    let _ = write!(phrase, "{}", num);
+}
 
-    phrase
+/// push_suffix will append the numerical suffix of a word to the phrase, using itoa to format the
+/// number.
+#[cfg(feature = "fast_fmt")]
+fn push_suffix(phrase: &mut String, num: u8) {
+    let mut buffer = itoa::Buffer::new();
+    phrase.push_str(buffer.format(num));
 }
 
 /// phrase_to_binary is the inverse of binary_to_phrase, it will take a mnonmic-16bit phrase and
 /// parse it into a set of bytes.
+///
+/// ```
+/// use mnemonic_16bit::phrase_to_binary;
+///
+/// assert!(phrase_to_binary("abbey0 ace64").unwrap() == [0, 0, 5]);
+/// assert!(phrase_to_binary("abbey").is_err());
+/// ```
 pub fn phrase_to_binary(phrase: &str) -> Result<Vec<u8>, Error> {
-    if phrase == "" {
+    if phrase.is_empty() {
         return Ok(vec![0u8; 0]);
     }
 
@@ -78,7 +131,7 @@ pub fn phrase_to_binary(phrase: &str) -> Result<Vec<u8>, Error> {
     let mut finalized = false;
     let mut result: Vec<u8> = Vec::new();
     let words = phrase.split(" ");
-    for word in words {
+    for (i, word) in words.enumerate() {
         if finalized {
             bail!("only the last word may contain the number '64'");
         }
@@ -99,15 +152,19 @@ pub fn phrase_to_binary(phrase: &str) -> Result<Vec<u8>, Error> {
         if digits == 0 {
             bail!("word must have a numerical suffix");
         }
+        // The dictionary lookup slices the word by byte offset, so only ascii words can be passed
+        // to it safely.
+        if !word.is_ascii() {
+            bail!("word {} contains non-ascii characters", i);
+        }
 
         // We have validated the word, now we need to parse the bytes. We start with the numerical
         // suffix because that indicates whether we are pulling 8 bits from the word or 10.
-        let numerical_suffix;
-        if digits == 1 {
-            numerical_suffix = &word[word.len()-1..];
+        let numerical_suffix = if digits == 1 {
+            &word[word.len()-1..]
         } else {
-            numerical_suffix = &word[word.len()-2..];
-        }
+            &word[word.len()-2..]
+        };
 
         // Parse the rest of the data based on whether the final digit is 64 or less.
         if numerical_suffix == "64" {
@@ -118,13 +175,18 @@ pub fn phrase_to_binary(phrase: &str) -> Result<Vec<u8>, Error> {
             }
             result.push(word_index as u8);
         } else {
-            let mut bits = index_of_word(word).context(format!("invalid word {} in phrase", word))? as u16;
-            bits *= 64;
-            let numerical_bits: u16 = numerical_suffix.parse().unwrap();
+            let word_index = index_of_word(word).context(format!("invalid word {} in phrase", word))?;
+            let numerical_bits: u16 = numerical_suffix
+                .parse()
+                .context(format!("invalid numerical suffix in word {}", i))?;
             if numerical_bits > 64 {
                 bail!("numerical suffix must have a value [0, 64]");
             }
-            bits += numerical_bits;
+            let bits = u16::try_from(word_index)
+                .ok()
+                .and_then(|bits| bits.checked_mul(64))
+                .and_then(|bits| bits.checked_add(numerical_bits))
+                .context(format!("arithmetic overflow while decoding word {}", i))?;
             result.push((bits / 256) as u8);
             result.push((bits % 256) as u8);
         }
@@ -133,75 +195,3336 @@ pub fn phrase_to_binary(phrase: &str) -> Result<Vec<u8>, Error> {
     Ok(result)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use userspace_rng::Csprng;
-    use rand_core::RngCore;
+/// binary_to_phrase_with_positions will convert a binary string to a phrase where every word is
+/// prefixed by its 1-based position in the phrase, e.g. "1:abbey0 2:sugar21 3:mob32". This is
+/// useful for numbered-list displays such as those found on hardware wallets.
+///
+/// ```
+/// use mnemonic_16bit::binary_to_phrase_with_positions;
+///
+/// assert!(binary_to_phrase_with_positions(&[0, 0, 5]) == "1:abbey0 2:ace64");
+/// ```
+pub fn binary_to_phrase_with_positions(data: &[u8]) -> String {
+    let phrase = binary_to_phrase(data);
+    if phrase.is_empty() {
+        return phrase;
+    }
 
-    #[test]
-    // Try a bunch of binary arrays and see that they all correctly convert into phrases and then
-    // back into the same binary.
-    fn check_seed_phrases() {
-        // Try empty array.
-        let basic = [0u8; 0];
-        let phrase = binary_to_phrase(&basic);
-        let result = phrase_to_binary(&phrase).unwrap();
-        assert!(basic[..] == result[..]);
+    let words: Vec<String> = phrase
+        .split(' ')
+        .enumerate()
+        .map(|(i, word)| format!("{}:{}", i+1, word))
+        .collect();
+    words.join(" ")
+}
 
-        // Try all possible 1 byte values.
-        for i in 0..=255 {
-            let basic = [i as u8; 1];
-            let phrase = binary_to_phrase(&basic);
-            let result = phrase_to_binary(&phrase).unwrap();
-            assert!(basic[..] == result[..]);
+/// phrase_to_binary_with_positions is the inverse of binary_to_phrase_with_positions. Each word
+/// must be prefixed by its 1-based position and a colon, the prefixes are stripped before the
+/// phrase is parsed.
+///
+/// ```
+/// use mnemonic_16bit::phrase_to_binary_with_positions;
+///
+/// assert!(phrase_to_binary_with_positions("1:abbey0 2:ace64").unwrap() == [0, 0, 5]);
+/// assert!(phrase_to_binary_with_positions("2:abbey0 1:ace64").is_err());
+/// ```
+pub fn phrase_to_binary_with_positions(phrase: &str) -> Result<Vec<u8>, Error> {
+    if phrase.is_empty() {
+        return Ok(vec![0u8; 0]);
+    }
+
+    // Strip the position prefix from each word, checking that the positions are in order.
+    let mut words = Vec::new();
+    for (i, word) in phrase.split(' ').enumerate() {
+        let (position, word) = word.split_once(':').context(format!("word {} is missing its position prefix", i+1))?;
+        let position: usize = position.parse().context(format!("invalid position prefix '{}'", position))?;
+        if position != i+1 {
+            bail!("word {} has position prefix {}", i+1, position);
         }
+        words.push(word);
+    }
+    phrase_to_binary(&words.join(" "))
+}
 
-        // Try zero values for all possible array sizes 0-255.
-        for i in 0..=255 {
-            let basic = vec![0u8; i];
-            let phrase = binary_to_phrase(&basic);
-            let result = phrase_to_binary(&phrase).unwrap();
-            assert!(basic[..] == result[..]);
+/// token_key splits a token into the dictionary index of its word and its numerical suffix, if it
+/// has one. None is returned if the word is not in the dictionary or the suffix is not a number.
+fn token_key(token: &str) -> Option<(usize, Option<u8>)> {
+    let word = token.trim_end_matches(|c: char| c.is_ascii_digit());
+    if !word.is_ascii() {
+        return None;
+    }
+    let index = index_of_word(word).ok()?;
+    let suffix = &token[word.len()..];
+    let suffix = if suffix.is_empty() { None } else { Some(suffix.parse().ok()?) };
+    Some((index, suffix))
+}
+
+/// token_matches reports whether a phrase token matches a query. Words are compared the same way
+/// the decoder reads them, by their dictionary index, so "sug21" and "sugary21" both match
+/// "sugar21". A query with a numerical suffix must also match the suffix of the token, while a
+/// bare word matches the token regardless of its suffix.
+fn token_matches(token: &str, query: &str) -> bool {
+    match (token_key(token), token_key(query)) {
+        (Some((token_index, token_suffix)), Some((query_index, query_suffix))) => {
+            token_index == query_index && (query_suffix.is_none() || query_suffix == token_suffix)
         }
+        _ => false,
+    }
+}
 
-        // Try random data for all array sizes 0-255, 8 variations each size.
-        let mut rng = Csprng {};
-        for _ in 0..8 {
-            for i in 0..=255 {
-                let mut basic = vec![0u8; i];
-                rng.fill_bytes(&mut basic);
-                let phrase = binary_to_phrase(&basic);
-                let result = phrase_to_binary(&phrase).unwrap();
-                assert!(basic[..] == result[..]);
-            }
+/// phrase_contains_word will check whether a word appears anywhere in a phrase. The word may
+/// either be a full token such as "abbey0", which must match exactly, or a bare word such as
+/// "abbey", which matches that word with any numerical suffix. Like the decoder, only the first 3
+/// letters of a word are significant, so "sug21" matches "sugar". An error is returned if the
+/// phrase is not valid.
+///
+/// ```
+/// use mnemonic_16bit::phrase_contains_word;
+///
+/// assert!(phrase_contains_word("abbey0 ace64", "ace").unwrap());
+/// assert!(phrase_contains_word("abbey0 ace64", "abbey0").unwrap());
+/// assert!(!phrase_contains_word("abbey0 ace64", "abbey1").unwrap());
+/// ```
+pub fn phrase_contains_word(phrase: &str, word: &str) -> Result<bool, Error> {
+    phrase_to_binary(phrase)?;
+    Ok(phrase.split_whitespace().any(|token| token_matches(token, word)))
+}
+
+/// phrase_word_positions will return the 0-based index of every word in the phrase that matches
+/// word_token. Matching follows the same rules as phrase_contains_word: a full token must match
+/// exactly, and a bare word matches that word with any numerical suffix. Words are compared by
+/// their first 3 letters.
+///
+/// ```
+/// use mnemonic_16bit::phrase_word_positions;
+///
+/// assert!(phrase_word_positions("abbey0 abbey1 ace64", "abbey").unwrap() == [0, 1]);
+/// assert!(phrase_word_positions("abbey0 abbey1 ace64", "abbey1").unwrap() == [1]);
+/// ```
+pub fn phrase_word_positions(phrase: &str, word_token: &str) -> Result<Vec<usize>, Error> {
+    phrase_to_binary(phrase)?;
+    let positions = phrase
+        .split_whitespace()
+        .enumerate()
+        .filter(|(_, token)| token_matches(token, word_token))
+        .map(|(i, _)| i)
+        .collect();
+    Ok(positions)
+}
+
+/// phrase_is_palindrome will decode the phrase and check whether the resulting bytes read the same
+/// forwards and backwards.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_is_palindrome};
+///
+/// assert!(phrase_is_palindrome(&binary_to_phrase(&[1, 2, 1])).unwrap());
+/// assert!(!phrase_is_palindrome(&binary_to_phrase(&[1, 2, 3])).unwrap());
+/// ```
+pub fn phrase_is_palindrome(phrase: &str) -> Result<bool, Error> {
+    let data = phrase_to_binary(phrase)?;
+    Ok(data.iter().eq(data.iter().rev()))
+}
+
+/// phrase_popcount will decode the phrase and return the total number of 1-bits in the resulting
+/// bytes. Uniformly random data should have a popcount close to 4 times the number of bytes.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_popcount};
+///
+/// assert!(phrase_popcount(&binary_to_phrase(&[0xff, 0x01])).unwrap() == 9);
+/// ```
+pub fn phrase_popcount(phrase: &str) -> Result<u32, Error> {
+    let data = phrase_to_binary(phrase)?;
+    Ok(data.iter().map(|b| b.count_ones()).sum())
+}
+
+/// byte_histogram counts how many times each byte value appears in the data.
+fn byte_histogram(data: &[u8]) -> [u32; 256] {
+    let mut counts = [0u32; 256];
+    for b in data {
+        counts[*b as usize] += 1;
+    }
+    counts
+}
+
+/// shannon_entropy computes the Shannon entropy of a set of values in bits per value. It is 0 for
+/// an empty set.
+fn shannon_entropy(values: &[u8]) -> f64 {
+    let counts = byte_histogram(values);
+    let total = values.len() as f64;
+    let mut entropy = 0f64;
+    for count in counts.iter().filter(|c| **c > 0) {
+        let p = *count as f64 / total;
+        entropy -= p * p.log2();
+    }
+    entropy
+}
+
+/// estimate_entropy_bits gives a rough estimate of the entropy in a byte string by computing the
+/// Shannon entropy over the byte frequencies and multiplying by the length of the data. The same
+/// is done for the differences between neighbouring bytes, and the lower of the two estimates is
+/// used so that sequential data such as 0, 1, 2, 3 is caught as well as repeated data.
+fn estimate_entropy_bits(data: &[u8]) -> u32 {
+    let deltas: Vec<u8> = data.windows(2).map(|w| w[1].wrapping_sub(w[0])).collect();
+    let bits_per_byte = shannon_entropy(data).min(shannon_entropy(&deltas));
+    (bits_per_byte * data.len() as f64) as u32
+}
+
+/// binary_to_phrase_with_entropy_check will convert a binary string to a phrase, returning an
+/// error if the estimated entropy of the data is below min_entropy_bits. The estimate is based on
+/// the byte frequencies and on the differences between neighbouring bytes, so it catches weak
+/// inputs such as all zeroes or counting sequences but cannot prove that data is random.
+///
+/// Because the estimate is computed from the data itself, it can never exceed
+/// len * log2(min(len, 256)) bits. A 16 byte key is capped at 64 bits and a 32 byte key at 160
+/// bits, and only inputs of several thousand bytes come close to 8 bits per byte. Choose
+/// min_entropy_bits with that ceiling in mind rather than from the nominal size of the key.
+///
+/// ```
+/// use mnemonic_16bit::binary_to_phrase_with_entropy_check;
+///
+/// assert!(binary_to_phrase_with_entropy_check(&[0u8; 16], 32).is_err());
+/// let sequential: Vec<u8> = (0..16).collect();
+/// assert!(binary_to_phrase_with_entropy_check(&sequential, 32).is_err());
+/// let key = [
+///     0x3a, 0xc1, 0x07, 0x94, 0x5e, 0xf2, 0x18, 0xab, 0x66, 0xd9, 0x20, 0x8f, 0x41, 0xbc, 0x73, 0xe5,
+/// ];
+/// assert!(binary_to_phrase_with_entropy_check(&key, 48).is_ok());
+/// assert!(binary_to_phrase_with_entropy_check(&key, 128).is_err());
+/// ```
+pub fn binary_to_phrase_with_entropy_check(data: &[u8], min_entropy_bits: u32) -> Result<String, Error> {
+    let estimated = estimate_entropy_bits(data);
+    if estimated < min_entropy_bits {
+        bail!("data has an estimated {} bits of entropy, but {} are required", estimated, min_entropy_bits);
+    }
+    Ok(binary_to_phrase(data))
+}
+
+/// binary_to_phrase_with_magic will prepend the magic bytes to the data before converting it to a
+/// phrase. Magic bytes are useful for identifying the type of data that a phrase holds.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, binary_to_phrase_with_magic};
+///
+/// assert!(binary_to_phrase_with_magic(&[5], &[0, 0]) == binary_to_phrase(&[0, 0, 5]));
+/// ```
+pub fn binary_to_phrase_with_magic(data: &[u8], magic: &[u8]) -> String {
+    binary_to_phrase(&[magic, data].concat())
+}
+
+/// phrase_to_binary_with_magic is the inverse of binary_to_phrase_with_magic. It will parse the
+/// phrase and strip the magic bytes, returning an error if the phrase does not start with the
+/// expected magic bytes.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase_with_magic, phrase_to_binary_with_magic};
+///
+/// let phrase = binary_to_phrase_with_magic(&[5], &[0, 0]);
+/// assert!(phrase_to_binary_with_magic(&phrase, &[0, 0]).unwrap() == [5]);
+/// assert!(phrase_to_binary_with_magic(&phrase, &[1, 0]).is_err());
+/// ```
+pub fn phrase_to_binary_with_magic(phrase: &str, magic: &[u8]) -> Result<Vec<u8>, Error> {
+    phrase_to_binary_checked_prefix(phrase, magic)
+}
+
+/// xor_phrases will decode both phrases and xor their bytes together, returning an error if the
+/// phrases decode to different lengths.
+fn xor_phrases(a: &str, b: &str) -> Result<Vec<u8>, Error> {
+    let a = phrase_to_binary(a)?;
+    let b = phrase_to_binary(b)?;
+    if a.len() != b.len() {
+        bail!("phrases have different lengths: {} bytes and {} bytes", a.len(), b.len());
+    }
+    Ok(a.iter().zip(b.iter()).map(|(x, y)| x ^ y).collect())
+}
+
+/// phrase_delta_encode will xor the bytes of curr against the bytes of prev and return the result
+/// as a phrase. Both phrases must decode to the same number of bytes. When consecutive phrases
+/// differ by only a few bits, the delta phrase is mostly made up of small values.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_delta_encode};
+///
+/// let prev = binary_to_phrase(&[1, 2]);
+/// let curr = binary_to_phrase(&[1, 3]);
+/// assert!(phrase_delta_encode(&prev, &curr).unwrap() == binary_to_phrase(&[0, 1]));
+/// ```
+pub fn phrase_delta_encode(prev: &str, curr: &str) -> Result<String, Error> {
+    Ok(binary_to_phrase(&xor_phrases(prev, curr)?))
+}
+
+/// phrase_delta_decode is the inverse of phrase_delta_encode. It will xor the bytes of delta
+/// against the bytes of prev to recover the current phrase.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_delta_decode, phrase_delta_encode};
+///
+/// let prev = binary_to_phrase(&[1, 2]);
+/// let curr = binary_to_phrase(&[1, 3]);
+/// let delta = phrase_delta_encode(&prev, &curr).unwrap();
+/// assert!(phrase_delta_decode(&prev, &delta).unwrap() == curr);
+/// ```
+pub fn phrase_delta_decode(prev: &str, delta: &str) -> Result<String, Error> {
+    Ok(binary_to_phrase(&xor_phrases(prev, delta)?))
+}
+
+/// binary_to_phrase_json will convert a binary string to a phrase and return it in a JSON object
+/// alongside its metadata, e.g. {"phrase": "abbey0", "word_count": 1, "byte_count": 2}.
+///
+/// ```
+/// use mnemonic_16bit::binary_to_phrase_json;
+///
+/// let value = binary_to_phrase_json(&[0, 0]);
+/// assert!(value["phrase"] == "abbey0");
+/// assert!(value["word_count"] == 1);
+/// assert!(value["byte_count"] == 2);
+/// ```
+#[cfg(feature = "serde_json")]
+pub fn binary_to_phrase_json(data: &[u8]) -> serde_json::Value {
+    let phrase = binary_to_phrase(data);
+    let word_count = phrase.split_whitespace().count();
+    serde_json::json!({
+        "phrase": phrase,
+        "word_count": word_count,
+        "byte_count": data.len(),
+    })
+}
+
+/// phrase_from_json is the inverse of binary_to_phrase_json. It will decode the "phrase" field of
+/// a JSON object, and if the "word_count" or "byte_count" fields are present it will check that
+/// they agree with the decoded phrase.
+///
+/// ```
+/// use mnemonic_16bit::phrase_from_json;
+///
+/// let value = serde_json::json!({"phrase": "abbey0", "byte_count": 2});
+/// assert!(phrase_from_json(&value).unwrap() == [0, 0]);
+/// ```
+#[cfg(feature = "serde_json")]
+pub fn phrase_from_json(value: &serde_json::Value) -> Result<Vec<u8>, Error> {
+    let phrase = value["phrase"].as_str().context("json object has no string field 'phrase'")?;
+    let data = phrase_to_binary(phrase)?;
+
+    // Validate the metadata if it was provided.
+    if let Some(word_count) = value.get("word_count") {
+        let actual = phrase.split_whitespace().count();
+        if word_count.as_u64() != Some(actual as u64) {
+            bail!("metadata mismatch: word_count is {} but phrase has {} words", word_count, actual);
+        }
+    }
+    if let Some(byte_count) = value.get("byte_count") {
+        if byte_count.as_u64() != Some(data.len() as u64) {
+            bail!("metadata mismatch: byte_count is {} but phrase has {} bytes", byte_count, data.len());
         }
+    }
+    Ok(data)
+}
 
-        // Try all possible 2 byte values.
-        for i in 0..=255 {
-            for j in 0..=255 {
-                let mut basic = [0u8; 2];
-                basic[0] = i;
-                basic[1] = j;
-                let phrase = binary_to_phrase(&basic);
-                let result = phrase_to_binary(&phrase).unwrap();
-                assert!(basic[..] == result[..]);
-            }
+/// phrase_to_binary_from_json_string will parse a JSON string and decode the phrase it contains,
+/// following the same rules as phrase_from_json.
+///
+/// ```
+/// use mnemonic_16bit::phrase_to_binary_from_json_string;
+///
+/// assert!(phrase_to_binary_from_json_string(r#"{"phrase": "abbey0"}"#).unwrap() == [0, 0]);
+/// assert!(phrase_to_binary_from_json_string("not json").is_err());
+/// ```
+#[cfg(feature = "serde_json")]
+pub fn phrase_to_binary_from_json_string(json: &str) -> Result<Vec<u8>, Error> {
+    let value: serde_json::Value = serde_json::from_str(json).context("invalid json")?;
+    phrase_from_json(&value)
+}
+
+/// binary_to_phrase_toml_line will convert a binary string to a phrase and return it as a TOML
+/// key-value line, e.g. `phrase = "abbey0 sugar21"`. The key is quoted if TOML requires it.
+///
+/// ```
+/// use mnemonic_16bit::binary_to_phrase_toml_line;
+///
+/// assert!(binary_to_phrase_toml_line(&[0, 0], "seed") == r#"seed = "abbey0""#);
+/// ```
+#[cfg(feature = "toml")]
+pub fn binary_to_phrase_toml_line(data: &[u8], key: &str) -> String {
+    let mut table = toml::Table::new();
+    table.insert(key.to_string(), toml::Value::String(binary_to_phrase(data)));
+    let line = toml::to_string(&table).expect("a table with one string value can always be serialized");
+    line.trim_end().to_string()
+}
+
+/// phrase_from_toml_line is the inverse of binary_to_phrase_toml_line. It will parse a single
+/// TOML key-value line and decode the phrase stored in its value, ignoring the key.
+///
+/// ```
+/// use mnemonic_16bit::phrase_from_toml_line;
+///
+/// assert!(phrase_from_toml_line(r#"seed = "abbey0""#).unwrap() == [0, 0]);
+/// ```
+#[cfg(feature = "toml")]
+pub fn phrase_from_toml_line(line: &str) -> Result<Vec<u8>, Error> {
+    let table: toml::Table = line.parse().context("invalid toml")?;
+    if table.len() != 1 {
+        bail!("toml line must contain exactly one key, found {}", table.len());
+    }
+    let (key, value) = table.iter().next().context("toml line has no key")?;
+    let phrase = value.as_str().context(format!("toml value for key '{}' is not a string", key))?;
+    phrase_to_binary(phrase)
+}
+
+/// binary_to_phrase_structured will convert a binary string into the list of (word, suffix) pairs
+/// that make up its phrase. This is more convenient for UI rendering than a phrase string, which
+/// would need to be parsed again.
+///
+/// ```
+/// use mnemonic_16bit::binary_to_phrase_structured;
+///
+/// let words = binary_to_phrase_structured(&[0, 0, 5]);
+/// assert!(words == [("abbey".to_string(), 0), ("ace".to_string(), 64)]);
+/// ```
+pub fn binary_to_phrase_structured(data: &[u8]) -> Vec<(String, u8)> {
+    word_indices(data.iter().copied())
+        .map(|(index, suffix)| (word_at_index(index), suffix))
+        .collect()
+}
+
+/// phrase_from_structured is the inverse of binary_to_phrase_structured. It will validate each
+/// (word, suffix) pair and join them into a phrase string.
+///
+/// ```
+/// use mnemonic_16bit::phrase_from_structured;
+///
+/// assert!(phrase_from_structured(&[("abbey", 0), ("ace", 64)]).unwrap() == "abbey0 ace64");
+/// assert!(phrase_from_structured(&[("abbey", 65)]).is_err());
+/// ```
+pub fn phrase_from_structured(tokens: &[(impl AsRef<str>, u8)]) -> Result<String, Error> {
+    let mut words = Vec::with_capacity(tokens.len());
+    for (i, (word, suffix)) in tokens.iter().enumerate() {
+        let word = word.as_ref();
+        if !word.chars().all(|c| c.is_ascii_lowercase()) {
+            bail!("word {} '{}' must only contain lowercase letters", i, word);
         }
+        index_of_word(word).context(format!("invalid word {} in phrase", word))?;
+        if *suffix > 64 {
+            bail!("numerical suffix must have a value [0, 64]");
+        }
+        words.push(format!("{}{}", word, suffix));
     }
 
-    #[test]
-    // Check a variety of invalid phrases.
-    fn check_bad_phrases() {
-        phrase_to_binary("a").unwrap_err();
-        phrase_to_binary("a64").unwrap_err();
-        phrase_to_binary("abbey").unwrap_err();
-        phrase_to_binary("abbey65").unwrap_err();
-        phrase_to_binary("yacht64").unwrap_err();
-        phrase_to_binary("sugar21 ab55 mob32").unwrap_err();
-        phrase_to_binary("sugar21 toffee mob32").unwrap_err();
+    // Check the joined phrase as a whole, which catches misplaced and out of range final words.
+    let phrase = words.join(" ");
+    phrase_to_binary(&phrase)?;
+    Ok(phrase)
+}
 
-        // This one should work even though we trucated the words.
-        phrase_to_binary("sug21 tof21 mob32").unwrap();
+/// phrase_to_binary_checked_prefix will parse the phrase and check that the bytes start with
+/// expected_prefix, returning only the bytes that follow the prefix. This is useful for versioned
+/// protocols where the first few bytes hold a version or type tag.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_to_binary_checked_prefix};
+///
+/// let phrase = binary_to_phrase(&[1, 7, 7]);
+/// assert!(phrase_to_binary_checked_prefix(&phrase, &[1]).unwrap() == [7, 7]);
+/// assert!(phrase_to_binary_checked_prefix(&phrase, &[2]).is_err());
+/// ```
+pub fn phrase_to_binary_checked_prefix(phrase: &str, expected_prefix: &[u8]) -> Result<Vec<u8>, Error> {
+    let data = phrase_to_binary(phrase)?;
+    if !data.starts_with(expected_prefix) {
+        let actual = &data[..expected_prefix.len().min(data.len())];
+        bail!("prefix mismatch: expected {:?}, got {:?}", expected_prefix, actual);
+    }
+    Ok(data[expected_prefix.len()..].to_vec())
+}
+
+/// phrase_to_binary_strip_prefix will parse the phrase and drop the first prefix_bytes bytes,
+/// without checking their values. An error is returned if the phrase holds fewer than
+/// prefix_bytes bytes.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_to_binary_strip_prefix};
+///
+/// let phrase = binary_to_phrase(&[1, 7, 7]);
+/// assert!(phrase_to_binary_strip_prefix(&phrase, 1).unwrap() == [7, 7]);
+/// assert!(phrase_to_binary_strip_prefix(&phrase, 4).is_err());
+/// ```
+pub fn phrase_to_binary_strip_prefix(phrase: &str, prefix_bytes: usize) -> Result<Vec<u8>, Error> {
+    let data = phrase_to_binary(phrase)?;
+    if data.len() < prefix_bytes {
+        bail!("phrase has {} bytes, which is shorter than the {} byte prefix", data.len(), prefix_bytes);
+    }
+    Ok(data[prefix_bytes..].to_vec())
+}
+
+/// binary_to_phrase_with_suffix_bytes will append the suffix bytes to the data before converting
+/// it to a phrase. The suffix could hold a version, checksum, or metadata tag. The result is the
+/// same as calling binary_to_phrase on the concatenation of data and suffix.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, binary_to_phrase_with_suffix_bytes};
+///
+/// assert!(binary_to_phrase_with_suffix_bytes(&[0, 0], &[5]) == binary_to_phrase(&[0, 0, 5]));
+/// ```
+pub fn binary_to_phrase_with_suffix_bytes(data: &[u8], suffix: &[u8]) -> String {
+    encode_bytes(data.iter().chain(suffix).copied())
+}
+
+/// phrase_to_binary_strip_suffix will parse the phrase and drop the last suffix_bytes bytes,
+/// without checking their values. An error is returned if the phrase holds fewer than
+/// suffix_bytes bytes.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase_with_suffix_bytes, phrase_to_binary_strip_suffix};
+///
+/// let phrase = binary_to_phrase_with_suffix_bytes(&[7, 7], &[1]);
+/// assert!(phrase_to_binary_strip_suffix(&phrase, 1).unwrap() == [7, 7]);
+/// assert!(phrase_to_binary_strip_suffix(&phrase, 4).is_err());
+/// ```
+pub fn phrase_to_binary_strip_suffix(phrase: &str, suffix_bytes: usize) -> Result<Vec<u8>, Error> {
+    let mut data = phrase_to_binary(phrase)?;
+    if data.len() < suffix_bytes {
+        bail!("phrase has {} bytes, which is shorter than the {} byte suffix", data.len(), suffix_bytes);
+    }
+    data.truncate(data.len() - suffix_bytes);
+    Ok(data)
+}
+
+/// phrase_embed_bit will set the bit at bit_index in the decoded bytes of the phrase to value and
+/// return the re-encoded phrase. Bits are numbered MSB-first within each byte, so bit 0 is the
+/// highest bit of the first byte. An error is returned if bit_index is out of bounds.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_embed_bit};
+///
+/// let phrase = binary_to_phrase(&[0, 0]);
+/// assert!(phrase_embed_bit(&phrase, 15, true).unwrap() == binary_to_phrase(&[0, 1]));
+/// assert!(phrase_embed_bit(&phrase, 16, true).is_err());
+/// ```
+pub fn phrase_embed_bit(phrase: &str, bit_index: usize, value: bool) -> Result<String, Error> {
+    let mut data = phrase_to_binary(phrase)?;
+    if bit_index >= data.len() * 8 {
+        bail!("bit index {} is out of bounds for a phrase with {} bits", bit_index, data.len() * 8);
+    }
+    let mask = 0x80 >> (bit_index % 8);
+    if value {
+        data[bit_index / 8] |= mask;
+    } else {
+        data[bit_index / 8] &= !mask;
+    }
+    Ok(binary_to_phrase(&data))
+}
+
+/// phrase_extract_bit will return the bit at bit_index in the decoded bytes of the phrase, using
+/// the same MSB-first numbering as phrase_embed_bit. An error is returned if bit_index is out of
+/// bounds.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_extract_bit};
+///
+/// let phrase = binary_to_phrase(&[0x80, 0x01]);
+/// assert!(phrase_extract_bit(&phrase, 0).unwrap());
+/// assert!(!phrase_extract_bit(&phrase, 1).unwrap());
+/// assert!(phrase_extract_bit(&phrase, 15).unwrap());
+/// ```
+pub fn phrase_extract_bit(phrase: &str, bit_index: usize) -> Result<bool, Error> {
+    let data = phrase_to_binary(phrase)?;
+    if bit_index >= data.len() * 8 {
+        bail!("bit index {} is out of bounds for a phrase with {} bits", bit_index, data.len() * 8);
+    }
+    Ok(data[bit_index / 8] & (0x80 >> (bit_index % 8)) != 0)
+}
+
+/// binary_to_phrase_with_salt will prepend a 4 byte salt to the data before converting it to a
+/// phrase. Using a fresh random salt for each call means the same data produces a different
+/// phrase every time it is encoded.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, binary_to_phrase_with_salt};
+///
+/// let phrase = binary_to_phrase_with_salt(&[5], &[0, 0, 0, 0]);
+/// assert!(phrase == binary_to_phrase(&[0, 0, 0, 0, 5]));
+/// ```
+pub fn binary_to_phrase_with_salt(data: &[u8], salt: &[u8; 4]) -> String {
+    encode_bytes(salt.iter().chain(data).copied())
+}
+
+/// phrase_to_binary_strip_salt is the inverse of binary_to_phrase_with_salt. It will parse the
+/// phrase and return the data along with the 4 byte salt that preceded it. An error is returned
+/// if the phrase holds fewer than 4 bytes.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase_with_salt, phrase_to_binary_strip_salt};
+///
+/// let phrase = binary_to_phrase_with_salt(&[5], &[1, 2, 3, 4]);
+/// let (data, salt) = phrase_to_binary_strip_salt(&phrase).unwrap();
+/// assert!(data == [5]);
+/// assert!(salt == [1, 2, 3, 4]);
+/// ```
+pub fn phrase_to_binary_strip_salt(phrase: &str) -> Result<(Vec<u8>, [u8; 4]), Error> {
+    let data = phrase_to_binary(phrase)?;
+    if data.len() < 4 {
+        bail!("phrase has {} bytes, which is too short to contain a 4 byte salt", data.len());
+    }
+    let salt = [data[0], data[1], data[2], data[3]];
+    Ok((data[4..].to_vec(), salt))
+}
+
+/// phrase_mask will xor the decoded bytes of the phrase with a repeating mask and return the
+/// re-encoded phrase. The mask wraps around if it is shorter than the phrase, and applying the
+/// same mask twice returns the original phrase. An error is returned if the mask is empty.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_mask};
+///
+/// let phrase = binary_to_phrase(&[1, 2, 3]);
+/// let masked = phrase_mask(&phrase, &[0xff]).unwrap();
+/// assert!(masked == binary_to_phrase(&[0xfe, 0xfd, 0xfc]));
+/// assert!(phrase_mask(&masked, &[0xff]).unwrap() == phrase);
+/// ```
+pub fn phrase_mask(phrase: &str, mask: &[u8]) -> Result<String, Error> {
+    if mask.is_empty() {
+        bail!("mask must not be empty");
+    }
+    phrase_xor_key_stream(phrase, mask.iter().copied().cycle())
+}
+
+/// phrase_xor_key_stream will xor the decoded bytes of the phrase with bytes taken from the key
+/// stream and return the re-encoded phrase. An error is returned if the key stream runs out
+/// before every byte of the phrase has been processed.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_xor_key_stream};
+///
+/// let phrase = binary_to_phrase(&[1, 2, 3]);
+/// let result = phrase_xor_key_stream(&phrase, [1, 2, 3].into_iter()).unwrap();
+/// assert!(result == binary_to_phrase(&[0, 0, 0]));
+/// assert!(phrase_xor_key_stream(&phrase, [1, 2].into_iter()).is_err());
+/// ```
+pub fn phrase_xor_key_stream(phrase: &str, mut key_stream: impl Iterator<Item = u8>) -> Result<String, Error> {
+    let mut data = phrase_to_binary(phrase)?;
+    for (i, b) in data.iter_mut().enumerate() {
+        let key = key_stream.next().context(format!("key stream exhausted after {} bytes", i))?;
+        *b ^= key;
+    }
+    Ok(binary_to_phrase(&data))
+}
+
+/// binary_to_phrase_zero_padded will left-pad the data with zero bytes to exactly target_bytes
+/// bytes before converting it to a phrase. An error is returned if the data is longer than
+/// target_bytes.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, binary_to_phrase_zero_padded};
+///
+/// let phrase = binary_to_phrase_zero_padded(&[5], 3).unwrap();
+/// assert!(phrase == binary_to_phrase(&[0, 0, 5]));
+/// assert!(binary_to_phrase_zero_padded(&[1, 2], 1).is_err());
+/// ```
+pub fn binary_to_phrase_zero_padded(data: &[u8], target_bytes: usize) -> Result<String, Error> {
+    if data.len() > target_bytes {
+        bail!("data has {} bytes, which does not fit in {} bytes", data.len(), target_bytes);
+    }
+    let padding = std::iter::repeat_n(0u8, target_bytes - data.len());
+    Ok(encode_bytes(padding.chain(data.iter().copied())))
+}
+
+/// binary_to_phrase_zero_stripped will drop any leading zero bytes from the data before
+/// converting it to a phrase, producing shorter phrases for small big-endian values. The number
+/// of stripped bytes is not recorded, use phrase_to_binary_zero_padded with the original length
+/// to restore them.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, binary_to_phrase_zero_stripped};
+///
+/// assert!(binary_to_phrase_zero_stripped(&[0, 0, 0, 5]) == binary_to_phrase(&[5]));
+/// ```
+pub fn binary_to_phrase_zero_stripped(data: &[u8]) -> String {
+    encode_bytes(data.iter().copied().skip_while(|b| *b == 0))
+}
+
+/// phrase_to_binary_zero_padded will parse the phrase and left-pad the result with zero bytes to
+/// exactly expected_bytes bytes. This is the inverse of binary_to_phrase_zero_stripped. An error
+/// is returned if the phrase holds more than expected_bytes bytes.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase_zero_stripped, phrase_to_binary_zero_padded};
+///
+/// let phrase = binary_to_phrase_zero_stripped(&[0, 0, 0, 5]);
+/// assert!(phrase_to_binary_zero_padded(&phrase, 4).unwrap() == [0, 0, 0, 5]);
+/// ```
+pub fn phrase_to_binary_zero_padded(phrase: &str, expected_bytes: usize) -> Result<Vec<u8>, Error> {
+    let data = phrase_to_binary(phrase)?;
+    if data.len() > expected_bytes {
+        bail!("phrase has {} bytes, which does not fit in {} bytes", data.len(), expected_bytes);
+    }
+    let mut result = vec![0u8; expected_bytes - data.len()];
+    result.extend(data);
+    Ok(result)
+}
+
+/// phrase_words splits a phrase into words the same way phrase_to_binary does: words are separated
+/// by single spaces, and an empty phrase has no words. Extra whitespace is not skipped, it shows up
+/// as empty or malformed words which the decoder then rejects.
+fn phrase_words(phrase: &str) -> impl DoubleEndedIterator<Item = &str> {
+    let words = if phrase.is_empty() { None } else { Some(phrase.split(' ')) };
+    words.into_iter().flatten()
+}
+
+/// phrase_to_binary_max_words will parse the phrase, returning an error without decoding anything
+/// if the phrase has more than max_words words.
+///
+/// ```
+/// use mnemonic_16bit::phrase_to_binary_max_words;
+///
+/// assert!(phrase_to_binary_max_words("abbey0 ace64", 2).unwrap() == [0, 0, 5]);
+/// assert!(phrase_to_binary_max_words("abbey0 ace64", 1).is_err());
+/// ```
+pub fn phrase_to_binary_max_words(phrase: &str, max_words: usize) -> Result<Vec<u8>, Error> {
+    let words = phrase_words(phrase).count();
+    if words > max_words {
+        bail!("phrase has {} words, but the limit is {}", words, max_words);
+    }
+    phrase_to_binary(phrase)
+}
+
+/// phrase_to_binary_exact_words will parse the phrase, returning an error without decoding
+/// anything if the phrase does not have exactly expected_words words. This prevents truncated or
+/// extended phrases from being accepted.
+///
+/// ```
+/// use mnemonic_16bit::phrase_to_binary_exact_words;
+///
+/// assert!(phrase_to_binary_exact_words("abbey0 ace64", 2).unwrap() == [0, 0, 5]);
+/// assert!(phrase_to_binary_exact_words("abbey0 ace64", 3).is_err());
+/// ```
+pub fn phrase_to_binary_exact_words(phrase: &str, expected_words: usize) -> Result<Vec<u8>, Error> {
+    let words = phrase_words(phrase).count();
+    if words != expected_words {
+        bail!("phrase has {} words, but {} were expected", words, expected_words);
+    }
+    phrase_to_binary(phrase)
+}
+
+/// phrase_first_word will return the first word of the phrase, including its numerical suffix, or
+/// None if the phrase is empty. The phrase is not validated.
+///
+/// ```
+/// use mnemonic_16bit::phrase_first_word;
+///
+/// assert!(phrase_first_word("abbey0 ace64") == Some("abbey0"));
+/// assert!(phrase_first_word("") == None);
+/// ```
+pub fn phrase_first_word(phrase: &str) -> Option<&str> {
+    phrase.split_whitespace().next()
+}
+
+/// phrase_last_word will return the last word of the phrase, including its numerical suffix, or
+/// None if the phrase is empty. The phrase is not validated.
+///
+/// ```
+/// use mnemonic_16bit::phrase_last_word;
+///
+/// assert!(phrase_last_word("abbey0 ace64") == Some("ace64"));
+/// assert!(phrase_last_word("") == None);
+/// ```
+pub fn phrase_last_word(phrase: &str) -> Option<&str> {
+    phrase.split_whitespace().next_back()
+}
+
+/// phrase_has_final_byte will check whether the last word of the phrase has the numerical suffix
+/// 64, meaning it only holds one byte. None is returned for an empty phrase. The rest of the
+/// phrase is not validated.
+///
+/// ```
+/// use mnemonic_16bit::phrase_has_final_byte;
+///
+/// assert!(phrase_has_final_byte("abbey0 ace64") == Some(true));
+/// assert!(phrase_has_final_byte("abbey0") == Some(false));
+/// assert!(phrase_has_final_byte("") == None);
+/// ```
+pub fn phrase_has_final_byte(phrase: &str) -> Option<bool> {
+    phrase_last_word(phrase).map(|word| word.ends_with("64"))
+}
+
+/// phrase_is_even_bytes will check whether the phrase holds an even number of bytes, which is the
+/// case when its last word does not have the numerical suffix 64. None is returned for an empty
+/// phrase. The rest of the phrase is not validated.
+///
+/// ```
+/// use mnemonic_16bit::phrase_is_even_bytes;
+///
+/// assert!(phrase_is_even_bytes("abbey0") == Some(true));
+/// assert!(phrase_is_even_bytes("abbey0 ace64") == Some(false));
+/// assert!(phrase_is_even_bytes("") == None);
+/// ```
+pub fn phrase_is_even_bytes(phrase: &str) -> Option<bool> {
+    phrase_has_final_byte(phrase).map(|has_final_byte| !has_final_byte)
+}
+
+/// phrase_byte_at will return the byte at byte_index. The whole phrase is checked so that it
+/// accepts exactly the phrases that phrase_to_binary accepts, e.g. a phrase whose first word holds
+/// a final byte is rejected even if a later byte is requested. An error is returned if byte_index
+/// is out of bounds.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_byte_at};
+///
+/// let phrase = binary_to_phrase(&[1, 2, 3]);
+/// assert!(phrase_byte_at(&phrase, 2).unwrap() == 3);
+/// assert!(phrase_byte_at(&phrase, 3).is_err());
+/// assert!(phrase_byte_at("ace64 abbey0", 0).is_err());
+/// ```
+pub fn phrase_byte_at(phrase: &str, byte_index: usize) -> Result<u8, Error> {
+    let data = phrase_to_binary(phrase)?;
+    data.get(byte_index).copied().context(format!("byte index {} is out of bounds", byte_index))
+}
+
+/// binary_to_phrase_words_only will return the dictionary word for each word of the phrase,
+/// without the numerical suffixes. This is lossy: the lower 6 bits of every second byte are
+/// dropped, use binary_to_phrase_suffixes_only to get them separately.
+///
+/// ```
+/// use mnemonic_16bit::binary_to_phrase_words_only;
+///
+/// assert!(binary_to_phrase_words_only(&[0, 0, 5]) == ["abbey", "ace"]);
+/// ```
+pub fn binary_to_phrase_words_only(data: &[u8]) -> Vec<&'static str> {
+    word_indices(data.iter().copied()).map(|(index, _)| DICTIONARY[index]).collect()
+}
+
+/// binary_to_phrase_suffixes_only will return the numerical suffix for each word of the phrase,
+/// without the words. The suffix is 64 for a final word that only holds one byte.
+///
+/// ```
+/// use mnemonic_16bit::binary_to_phrase_suffixes_only;
+///
+/// assert!(binary_to_phrase_suffixes_only(&[0, 7, 5]) == [7, 64]);
+/// ```
+pub fn binary_to_phrase_suffixes_only(data: &[u8]) -> Vec<u8> {
+    word_indices(data.iter().copied()).map(|(_, suffix)| suffix).collect()
+}
+
+/// phrase_to_binary_from_words_and_suffixes is the inverse of binary_to_phrase_words_only and
+/// binary_to_phrase_suffixes_only. It will validate and recombine the words and suffixes, then
+/// parse the result. The words and suffixes must have the same length.
+///
+/// ```
+/// use mnemonic_16bit::phrase_to_binary_from_words_and_suffixes;
+///
+/// let data = phrase_to_binary_from_words_and_suffixes(&["abbey", "ace"], &[7, 64]).unwrap();
+/// assert!(data == [0, 7, 5]);
+/// ```
+pub fn phrase_to_binary_from_words_and_suffixes(words: &[&str], suffixes: &[u8]) -> Result<Vec<u8>, Error> {
+    if words.len() != suffixes.len() {
+        bail!("got {} words but {} suffixes", words.len(), suffixes.len());
+    }
+    let tokens: Vec<(&str, u8)> = words.iter().copied().zip(suffixes.iter().copied()).collect();
+    phrase_to_binary(&phrase_from_structured(&tokens)?)
+}
+
+/// phrase_count_zeroes will decode the phrase and return the number of zero bytes. A large share
+/// of zero bytes can indicate data that was never properly randomized.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_count_zeroes};
+///
+/// assert!(phrase_count_zeroes(&binary_to_phrase(&[0, 1, 0])).unwrap() == 2);
+/// ```
+pub fn phrase_count_zeroes(phrase: &str) -> Result<u32, Error> {
+    let data = phrase_to_binary(phrase)?;
+    Ok(data.iter().filter(|b| **b == 0).count() as u32)
+}
+
+/// phrase_run_length will decode the phrase and return the run-length encoding of the bytes as
+/// (value, count) pairs. Random data is mostly made up of runs of length 1, long runs point to
+/// low entropy input or a repeating pattern.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_run_length};
+///
+/// let phrase = binary_to_phrase(&[7, 7, 7, 1, 7]);
+/// assert!(phrase_run_length(&phrase).unwrap() == [(7, 3), (1, 1), (7, 1)]);
+/// ```
+pub fn phrase_run_length(phrase: &str) -> Result<Vec<(u8, u32)>, Error> {
+    let data = phrase_to_binary(phrase)?;
+    let mut runs: Vec<(u8, u32)> = Vec::new();
+    for b in data {
+        match runs.last_mut() {
+            Some((value, count)) if *value == b => *count += 1,
+            _ => runs.push((b, 1)),
+        }
+    }
+    Ok(runs)
+}
+
+/// phrase_monotone_score will decode the phrase and return the fraction of consecutive byte pairs
+/// where the second byte is at least as large as the first. Random data scores close to 0.5, while
+/// sorted or sequential data scores close to 1.0. A phrase with fewer than two bytes has no pairs
+/// and scores 0.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_monotone_score};
+///
+/// assert!(phrase_monotone_score(&binary_to_phrase(&[1, 2, 3, 4, 5])).unwrap() == 1.0);
+/// assert!(phrase_monotone_score(&binary_to_phrase(&[1, 2, 1])).unwrap() == 0.5);
+/// ```
+pub fn phrase_monotone_score(phrase: &str) -> Result<f64, Error> {
+    let data = phrase_to_binary(phrase)?;
+    if data.len() < 2 {
+        return Ok(0.0);
+    }
+    let monotone = data.windows(2).filter(|pair| pair[0] <= pair[1]).count();
+    Ok(monotone as f64 / (data.len() - 1) as f64)
+}
+
+/// binary_to_phrase_chunks_labeled will split the data into chunks of chunk_bytes bytes and
+/// convert each chunk to a phrase prefixed with the label and its 1-based chunk number, e.g.
+/// "KEY-1: abbey0 sugar21". This is useful for backups that span multiple pages. Like
+/// slice::chunks, this will panic if chunk_bytes is 0.
+///
+/// ```
+/// use mnemonic_16bit::binary_to_phrase_chunks_labeled;
+///
+/// let chunks = binary_to_phrase_chunks_labeled(&[0, 0, 5], 2, "KEY");
+/// assert!(chunks == ["KEY-1: abbey0", "KEY-2: ace64"]);
+/// ```
+pub fn binary_to_phrase_chunks_labeled(data: &[u8], chunk_bytes: usize, label: &str) -> Vec<String> {
+    data.chunks(chunk_bytes)
+        .enumerate()
+        .map(|(i, chunk)| format!("{}-{}: {}", label, i+1, binary_to_phrase(chunk)))
+        .collect()
+}
+
+/// phrase_chunks_labeled_decode is the inverse of binary_to_phrase_chunks_labeled. It will strip
+/// the "label-N: " prefix from each chunk, decode the phrases, and concatenate the results in
+/// chunk number order. The chunks may be given in any order, but the numbers must run from 1 to
+/// the number of chunks without gaps or duplicates.
+///
+/// ```
+/// use mnemonic_16bit::phrase_chunks_labeled_decode;
+///
+/// let data = phrase_chunks_labeled_decode(&["KEY-2: ace64", "KEY-1: abbey0"], "KEY").unwrap();
+/// assert!(data == [0, 0, 5]);
+/// assert!(phrase_chunks_labeled_decode(&["KEY-2: ace64"], "KEY").is_err());
+/// ```
+pub fn phrase_chunks_labeled_decode(chunks: &[&str], label: &str) -> Result<Vec<u8>, Error> {
+    // Parse the chunk number and phrase out of each chunk.
+    let mut numbered: Vec<(usize, &str)> = Vec::with_capacity(chunks.len());
+    for chunk in chunks {
+        let rest = chunk
+            .strip_prefix(label)
+            .and_then(|rest| rest.strip_prefix('-'))
+            .context(format!("chunk '{}' does not start with label '{}-'", chunk, label))?;
+        let (number, phrase) = rest.split_once(':').context(format!("chunk '{}' is missing a ':'", chunk))?;
+        let number: usize = number.parse().context(format!("invalid chunk number '{}'", number))?;
+        if number == 0 {
+            bail!("chunk numbers start at 1");
+        }
+        numbered.push((number, phrase.strip_prefix(' ').unwrap_or(phrase)));
+    }
+
+    // Check that the chunk numbers are sequential.
+    numbered.sort_by_key(|(number, _)| *number);
+    for (i, (number, _)) in numbered.iter().enumerate() {
+        if *number < i+1 {
+            bail!("duplicate chunk {}", number);
+        }
+        if *number > i+1 {
+            bail!("missing chunk {}", i+1);
+        }
+    }
+
+    let mut result = Vec::new();
+    for (_, phrase) in numbered {
+        result.extend(phrase_to_binary(phrase)?);
+    }
+    Ok(result)
+}
+
+/// parse_phrase_allow_no_suffix is a lenient version of phrase_to_binary that accepts a final word
+/// without a numerical suffix, treating it as if it had the suffix 64. Some tools write "abbey"
+/// instead of "abbey64" for a final word that holds one byte. Every other word still needs a
+/// suffix.
+///
+/// ```
+/// use mnemonic_16bit::parse_phrase_allow_no_suffix;
+///
+/// assert!(parse_phrase_allow_no_suffix("abbey0 ace").unwrap() == [0, 0, 5]);
+/// assert!(parse_phrase_allow_no_suffix("abbey0 ace64").unwrap() == [0, 0, 5]);
+/// assert!(parse_phrase_allow_no_suffix("abbey ace64").is_err());
+/// ```
+pub fn parse_phrase_allow_no_suffix(phrase: &str) -> Result<Vec<u8>, Error> {
+    let has_suffix = phrase.ends_with(|c: char| c.is_ascii_digit());
+    if phrase.is_empty() || has_suffix {
+        return phrase_to_binary(phrase);
+    }
+    phrase_to_binary(&format!("{}64", phrase))
+}
+
+/// phrase_to_binary_and_zero will parse the phrase and then overwrite the memory of the phrase
+/// string with zeroes, leaving it empty. The string is zeroed whether or not parsing succeeds, so
+/// the phrase does not linger in memory after it has been used.
+///
+/// ```
+/// use mnemonic_16bit::phrase_to_binary_and_zero;
+///
+/// let mut phrase = "abbey0 ace64".to_string();
+/// assert!(phrase_to_binary_and_zero(&mut phrase).unwrap() == [0, 0, 5]);
+/// assert!(phrase.is_empty());
+/// ```
+#[cfg(feature = "zeroize")]
+pub fn phrase_to_binary_and_zero(phrase: &mut String) -> Result<Vec<u8>, Error> {
+    use zeroize::Zeroize;
+    let result = phrase_to_binary(phrase);
+    phrase.zeroize();
+    result
+}
+
+/// ALPHA_SUFFIXES maps the numerical suffixes 0-63 to the characters of the standard Base64
+/// alphabet for the alpha suffix notation.
+const ALPHA_SUFFIXES: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// ALPHA_FINAL_SUFFIX takes the place of the numerical suffix 64 in the alpha suffix notation. The
+/// Base64 padding character is used because '+' and '/' are already taken by 62 and 63.
+const ALPHA_FINAL_SUFFIX: char = '=';
+
+/// binary_to_phrase_alpha_suffix is an experimental alternative to binary_to_phrase that writes
+/// each numerical suffix as a single Base64 character, e.g. "abbeyA" instead of "abbey0". A final
+/// word that only holds one byte uses the suffix '='.
+///
+/// ```
+/// use mnemonic_16bit::binary_to_phrase_alpha_suffix;
+///
+/// assert!(binary_to_phrase_alpha_suffix(&[0, 0, 5]) == "abbeyA ace=");
+/// assert!(binary_to_phrase_alpha_suffix(&[0, 63]) == "abbey/");
+/// ```
+pub fn binary_to_phrase_alpha_suffix(data: &[u8]) -> String {
+    let words: Vec<String> = word_indices(data.iter().copied())
+        .map(|(index, suffix)| {
+            let suffix = match ALPHA_SUFFIXES.get(suffix as usize) {
+                Some(c) => *c as char,
+                None => ALPHA_FINAL_SUFFIX,
+            };
+            format!("{}{}", DICTIONARY[index], suffix)
+        })
+        .collect();
+    words.join(" ")
+}
+
+/// phrase_to_binary_alpha_suffix is the inverse of binary_to_phrase_alpha_suffix. Every word must
+/// end in a single Base64 suffix character, and only the last word may use the suffix '='. Since
+/// lowercase letters are also suffix characters, the last character of a word is always read as
+/// its suffix.
+///
+/// ```
+/// use mnemonic_16bit::phrase_to_binary_alpha_suffix;
+///
+/// assert!(phrase_to_binary_alpha_suffix("abbeyA ace=").unwrap() == [0, 0, 5]);
+/// assert!(phrase_to_binary_alpha_suffix("abbey= ace=").is_err());
+/// ```
+pub fn phrase_to_binary_alpha_suffix(phrase: &str) -> Result<Vec<u8>, Error> {
+    // Translate each word into the numerical notation, then parse the phrase as usual.
+    let mut words = Vec::new();
+    for word in phrase_words(phrase) {
+        let mut chars = word.chars();
+        let suffix = chars.next_back().context("empty word in phrase")?;
+        let base = chars.as_str();
+        if !base.chars().all(|c| c.is_ascii_lowercase()) {
+            bail!("word {} must be lowercase letters followed by a single suffix character", word);
+        }
+        let num = if suffix == ALPHA_FINAL_SUFFIX {
+            64
+        } else {
+            ALPHA_SUFFIXES
+                .iter()
+                .position(|c| *c as char == suffix)
+                .context(format!("word {} has invalid suffix '{}'", word, suffix))?
+        };
+        words.push(format!("{}{}", base, num));
+    }
+    phrase_to_binary(&words.join(" "))
+}
+
+/// dict_unique_prefix_length returns the number of leading characters that uniquely identify each
+/// word in the dictionary. Only this many characters of a word are looked at when parsing a
+/// phrase, so it is also the minimum length of a word.
+///
+/// ```
+/// use mnemonic_16bit::{dict_unique_prefix_length, phrase_to_binary};
+///
+/// assert!(dict_unique_prefix_length() == 3);
+/// assert!(phrase_to_binary("abb0").unwrap() == phrase_to_binary("abbey0").unwrap());
+/// ```
+pub fn dict_unique_prefix_length() -> usize {
+    DICTIONARY_UNIQUE_PREFIX
+}
+
+/// phrase_to_binary_words_only will parse a phrase whose words have no numerical suffixes, such as
+/// the output of binary_to_phrase_words_only, into byte_count bytes. The missing suffix bits are
+/// taken to be zero, so this is lossy for every second byte. The phrase must have exactly as many
+/// words as a phrase of byte_count bytes, and if byte_count is odd the last word holds the final
+/// byte just like a word with the suffix 64.
+///
+/// ```
+/// use mnemonic_16bit::phrase_to_binary_words_only;
+///
+/// assert!(phrase_to_binary_words_only("abbey ace", 3).unwrap() == [0, 0, 5]);
+/// assert!(phrase_to_binary_words_only("abbey ace", 2).is_err());
+/// ```
+pub fn phrase_to_binary_words_only(phrase: &str, byte_count: usize) -> Result<Vec<u8>, Error> {
+    let words: Vec<&str> = phrase_words(phrase).collect();
+    if words.len() != byte_count.div_ceil(2) {
+        bail!("{} bytes need {} words, but the phrase has {}", byte_count, byte_count.div_ceil(2), words.len());
+    }
+
+    // Give each word a zero suffix, or the final suffix if the last word holds a single byte.
+    let mut tokens = Vec::with_capacity(words.len());
+    for (i, word) in words.iter().enumerate() {
+        if !word.chars().all(|c| c.is_ascii_lowercase()) {
+            bail!("word {} must only contain lowercase letters", word);
+        }
+        let final_byte = i == words.len() - 1 && byte_count % 2 == 1;
+        tokens.push(format!("{}{}", word, if final_byte { 64 } else { 0 }));
+    }
+    phrase_to_binary(&tokens.join(" "))
+}
+
+/// phrase_to_binary_from_parts will parse a phrase that was entered in two halves, such as the
+/// left and right panels of a hardware wallet. The halves are joined with a space and parsed as a
+/// single phrase, so only the last word of the right half may use the suffix 64.
+///
+/// ```
+/// use mnemonic_16bit::phrase_to_binary_from_parts;
+///
+/// assert!(phrase_to_binary_from_parts("abbey0", "ace64").unwrap() == [0, 0, 5]);
+/// assert!(phrase_to_binary_from_parts("ace64", "abbey0").is_err());
+/// ```
+pub fn phrase_to_binary_from_parts(left: &str, right: &str) -> Result<Vec<u8>, Error> {
+    let parts: Vec<&str> = [left, right].into_iter().filter(|part| !part.is_empty()).collect();
+    phrase_to_binary(&parts.join(" "))
+}
+
+/// phrase_is_prefix_of will check whether the decoded bytes of short are a prefix of the decoded
+/// bytes of long. This is useful for hierarchical keys where child keys extend their parent key.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_is_prefix_of};
+///
+/// let parent = binary_to_phrase(&[1, 2, 3]);
+/// let child = binary_to_phrase(&[1, 2, 3, 4]);
+/// assert!(phrase_is_prefix_of(&parent, &child).unwrap());
+/// assert!(!phrase_is_prefix_of(&child, &parent).unwrap());
+/// ```
+pub fn phrase_is_prefix_of(short: &str, long: &str) -> Result<bool, Error> {
+    let short = phrase_to_binary(short)?;
+    let long = phrase_to_binary(long)?;
+    Ok(long.starts_with(&short))
+}
+
+/// phrase_common_prefix will return the longest common prefix of the decoded bytes of the two
+/// phrases, encoded as a phrase. Phrases that share no leading bytes return an empty phrase.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_common_prefix};
+///
+/// let a = binary_to_phrase(&[1, 2, 3]);
+/// let b = binary_to_phrase(&[1, 2, 4]);
+/// assert!(phrase_common_prefix(&a, &b).unwrap() == binary_to_phrase(&[1, 2]));
+/// ```
+pub fn phrase_common_prefix(a: &str, b: &str) -> Result<String, Error> {
+    let a = phrase_to_binary(a)?;
+    let b = phrase_to_binary(b)?;
+    let len = a.iter().zip(b.iter()).take_while(|(x, y)| x == y).count();
+    Ok(binary_to_phrase(&a[..len]))
+}
+
+/// phrase_to_binary_or_default will parse the phrase, falling back to a copy of default if the
+/// phrase is invalid. This is convenient when loading configuration where a bad phrase should not
+/// be fatal.
+///
+/// ```
+/// use mnemonic_16bit::phrase_to_binary_or_default;
+///
+/// assert!(phrase_to_binary_or_default("abbey0", &[9]) == [0, 0]);
+/// assert!(phrase_to_binary_or_default("abbey", &[9]) == [9]);
+/// ```
+pub fn phrase_to_binary_or_default(phrase: &str, default: &[u8]) -> Vec<u8> {
+    phrase_to_binary(phrase).unwrap_or_else(|_| default.to_vec())
+}
+
+/// phrase_to_binary_log_errors will parse the phrase, logging a warning with the error and
+/// returning None if the phrase is invalid. This is meant for production systems where a decoding
+/// failure should be recorded but should not be fatal.
+///
+/// ```
+/// use mnemonic_16bit::phrase_to_binary_log_errors;
+///
+/// assert!(phrase_to_binary_log_errors("abbey0") == Some(vec![0, 0]));
+/// assert!(phrase_to_binary_log_errors("abbey") == None);
+/// ```
+#[cfg(feature = "log")]
+pub fn phrase_to_binary_log_errors(phrase: &str) -> Option<Vec<u8>> {
+    match phrase_to_binary(phrase) {
+        Ok(data) => Some(data),
+        Err(e) => {
+            log::warn!("unable to decode phrase: {:#}", e);
+            None
+        }
+    }
+}
+
+/// phrase_to_binary_with_span will parse the phrase inside a "phrase_decode" tracing span that
+/// records the word_count and byte_count of the phrase. The span is returned alongside the data
+/// so that callers can keep using it, e.g. by entering it for follow-up work.
+///
+/// ```
+/// use mnemonic_16bit::phrase_to_binary_with_span;
+///
+/// let (data, span) = phrase_to_binary_with_span("abbey0").unwrap();
+/// assert!(data == [0, 0]);
+/// span.in_scope(|| {
+///     // Work here is recorded as part of the decode span.
+/// });
+/// ```
+#[cfg(feature = "tracing")]
+pub fn phrase_to_binary_with_span(phrase: &str) -> Result<(Vec<u8>, tracing::Span), Error> {
+    let word_count = phrase.split_whitespace().count();
+    let span = tracing::info_span!("phrase_decode", word_count, byte_count = tracing::field::Empty);
+    let data = span.in_scope(|| phrase_to_binary(phrase))?;
+    span.record("byte_count", data.len());
+    Ok((data, span))
+}
+
+/// binary_to_phrase_instrumented will convert a binary string to a phrase inside a
+/// "phrase_encode" tracing span that records the input_bytes and output_words of the conversion.
+///
+/// ```
+/// use mnemonic_16bit::binary_to_phrase_instrumented;
+///
+/// assert!(binary_to_phrase_instrumented(&[0, 0]) == "abbey0");
+/// ```
+#[cfg(feature = "tracing")]
+pub fn binary_to_phrase_instrumented(data: &[u8]) -> String {
+    let span = tracing::info_span!("phrase_encode", input_bytes = data.len(), output_words = tracing::field::Empty);
+    let phrase = span.in_scope(|| binary_to_phrase(data));
+    span.record("output_words", phrase.split_whitespace().count());
+    phrase
+}
+
+/// DEFAULT_PHRASE_CACHE_CAPACITY is the number of phrases that the decode cache of each thread
+/// holds until set_phrase_cache_capacity is called.
+#[cfg(feature = "cache")]
+const DEFAULT_PHRASE_CACHE_CAPACITY: usize = 64;
+
+#[cfg(feature = "cache")]
+thread_local! {
+    /// PHRASE_CACHE holds the most recently decoded phrases for phrase_to_binary_cache.
+    static PHRASE_CACHE: std::cell::RefCell<lru::LruCache<String, Vec<u8>>> =
+        std::cell::RefCell::new(lru::LruCache::new(
+            std::num::NonZeroUsize::new(DEFAULT_PHRASE_CACHE_CAPACITY).expect("default capacity is not zero"),
+        ));
+}
+
+/// phrase_to_binary_cache will parse the phrase, reusing the result if the same phrase was
+/// recently decoded on this thread. The cache is a per-thread LRU cache, and only phrases that
+/// decode successfully are stored.
+///
+/// ```
+/// use mnemonic_16bit::phrase_to_binary_cache;
+///
+/// assert!(phrase_to_binary_cache("abbey0").unwrap() == [0, 0]);
+/// assert!(phrase_to_binary_cache("abbey0").unwrap() == [0, 0]);
+/// ```
+#[cfg(feature = "cache")]
+pub fn phrase_to_binary_cache(phrase: &str) -> Result<Vec<u8>, Error> {
+    if let Some(data) = PHRASE_CACHE.with(|cache| cache.borrow_mut().get(phrase).cloned()) {
+        return Ok(data);
+    }
+    let data = phrase_to_binary(phrase)?;
+    PHRASE_CACHE.with(|cache| cache.borrow_mut().put(phrase.to_string(), data.clone()));
+    Ok(data)
+}
+
+/// set_phrase_cache_capacity will change the number of phrases held by the decode cache of the
+/// current thread, evicting the least recently used phrases if the cache shrinks. A capacity of 0
+/// is treated as 1.
+///
+/// ```
+/// use mnemonic_16bit::{phrase_to_binary_cache, set_phrase_cache_capacity};
+///
+/// set_phrase_cache_capacity(2);
+/// assert!(phrase_to_binary_cache("abbey0").unwrap() == [0, 0]);
+/// ```
+#[cfg(feature = "cache")]
+pub fn set_phrase_cache_capacity(n: usize) {
+    let capacity = std::num::NonZeroUsize::new(n).unwrap_or(std::num::NonZeroUsize::MIN);
+    PHRASE_CACHE.with(|cache| cache.borrow_mut().resize(capacity));
+}
+
+/// clear_phrase_cache will remove every phrase from the decode cache of the current thread, e.g.
+/// after a key rotation. It is safe to call even if the cache has never been used.
+///
+/// ```
+/// use mnemonic_16bit::{clear_phrase_cache, phrase_to_binary_cache};
+///
+/// clear_phrase_cache();
+/// assert!(phrase_to_binary_cache("abbey0").unwrap() == [0, 0]);
+/// clear_phrase_cache();
+/// ```
+#[cfg(feature = "cache")]
+pub fn clear_phrase_cache() {
+    PHRASE_CACHE.with(|cache| cache.borrow_mut().clear());
+}
+
+/// binary_to_phrase_checked_no_repetition will convert a binary string to a phrase, returning an
+/// error if any word appears more than once with the same numerical suffix. A repeated word is a
+/// hint that the input may have low entropy.
+///
+/// ```
+/// use mnemonic_16bit::binary_to_phrase_checked_no_repetition;
+///
+/// assert!(binary_to_phrase_checked_no_repetition(&[0, 0, 0, 1]).unwrap() == "abbey0 abbey1");
+/// assert!(binary_to_phrase_checked_no_repetition(&[0, 0, 0, 0]).is_err());
+/// ```
+pub fn binary_to_phrase_checked_no_repetition(data: &[u8]) -> Result<String, Error> {
+    let phrase = binary_to_phrase(data);
+    let mut seen = std::collections::HashMap::new();
+    for (i, token) in phrase.split_whitespace().enumerate() {
+        if let Some(first) = seen.insert(token, i) {
+            bail!("word {} at position {} repeats the word at position {}", token, i, first);
+        }
+    }
+    Ok(phrase)
+}
+
+/// phrase_byte_histogram will decode the phrase and return how many times each of the 256 byte
+/// values appears. Random data has a roughly flat histogram, while spikes indicate structure.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_byte_histogram};
+///
+/// let histogram = phrase_byte_histogram(&binary_to_phrase(&[7, 7, 9])).unwrap();
+/// assert!(histogram[7] == 2);
+/// assert!(histogram[9] == 1);
+/// assert!(histogram.iter().sum::<u32>() == 3);
+/// ```
+pub fn phrase_byte_histogram(phrase: &str) -> Result<[u32; 256], Error> {
+    let data = phrase_to_binary(phrase)?;
+    Ok(byte_histogram(&data))
+}
+
+/// hmac_tag computes HMAC-SHA256 of the data under the key and returns the first byte, which is
+/// used as the authentication tag of a phrase.
+#[cfg(feature = "hmac")]
+fn hmac_tag(data: &[u8], key: &[u8]) -> u8 {
+    use hmac::Mac;
+    let mut mac = hmac::Hmac::<sha2::Sha256>::new_from_slice(key).expect("hmac accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes()[0]
+}
+
+/// binary_to_phrase_with_hmac will append a 1 byte HMAC-SHA256 tag of the data under the key and
+/// convert the result to a phrase. When the data has an even number of bytes, the tag ends up in
+/// the final word with the numerical suffix 64. Use phrase_to_binary_with_hmac_verify to check
+/// the tag and recover the data.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase_with_hmac, phrase_to_binary_with_hmac_verify};
+///
+/// let phrase = binary_to_phrase_with_hmac(&[1, 2], b"key");
+/// assert!(phrase.ends_with("64"));
+/// assert!(phrase_to_binary_with_hmac_verify(&phrase, b"key").unwrap() == [1, 2]);
+/// assert!(phrase_to_binary_with_hmac_verify(&phrase, b"other key").is_err());
+/// ```
+#[cfg(feature = "hmac")]
+pub fn binary_to_phrase_with_hmac(data: &[u8], key: &[u8]) -> String {
+    binary_to_phrase_with_suffix_bytes(data, &[hmac_tag(data, key)])
+}
+
+/// phrase_to_binary_with_hmac_verify will parse a phrase whose last byte is a 1 byte
+/// HMAC-SHA256 tag of the data under the key. The tag is checked and stripped, and an error is
+/// returned if it does not match. A 1 byte tag only catches mistakes and casual tampering, it
+/// does not provide strong authentication.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_to_binary_with_hmac_verify};
+///
+/// assert!(phrase_to_binary_with_hmac_verify(&binary_to_phrase(&[1, 2]), b"key").is_err());
+/// ```
+#[cfg(feature = "hmac")]
+pub fn phrase_to_binary_with_hmac_verify(phrase: &str, key: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut data = phrase_to_binary(phrase)?;
+    let tag = data.pop().context("phrase is empty and has no hmac tag")?;
+    if hmac_tag(&data, key) != tag {
+        bail!("hmac tag of phrase does not match");
+    }
+    Ok(data)
+}
+
+/// phrase_from_usize will convert a usize, such as a memory address, into a phrase. The value is
+/// encoded big-endian using the native width of usize, so the phrase holds 4 bytes on 32-bit
+/// platforms and 8 bytes on 64-bit platforms.
+///
+/// ```
+/// use mnemonic_16bit::{phrase_from_usize, phrase_to_usize};
+///
+/// let phrase = phrase_from_usize(0xdead_beef);
+/// assert!(phrase_to_usize(&phrase).unwrap() == 0xdead_beef);
+/// ```
+pub fn phrase_from_usize(addr: usize) -> String {
+    binary_to_phrase(&addr.to_be_bytes())
+}
+
+/// phrase_to_usize is the inverse of phrase_from_usize. An error is returned if the phrase does
+/// not hold exactly as many bytes as a usize on the current platform.
+///
+/// ```
+/// use mnemonic_16bit::phrase_to_usize;
+///
+/// assert!(phrase_to_usize("abbey0").is_err());
+/// ```
+pub fn phrase_to_usize(phrase: &str) -> Result<usize, Error> {
+    let data = phrase_to_binary(phrase)?;
+    let mut bytes = [0u8; std::mem::size_of::<usize>()];
+    if data.len() != bytes.len() {
+        bail!("phrase has {} bytes, but a usize has {}", data.len(), bytes.len());
+    }
+    bytes.copy_from_slice(&data);
+    Ok(usize::from_be_bytes(bytes))
+}
+
+/// phrase_encode_timestamp will convert a timestamp into a phrase holding the number of seconds
+/// since the Unix epoch as a big-endian u64, which is always 4 words long. Sub-second precision is
+/// dropped, and times before the epoch are clamped to the epoch.
+///
+/// ```
+/// use mnemonic_16bit::{phrase_decode_timestamp, phrase_encode_timestamp};
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let ts = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+/// let phrase = phrase_encode_timestamp(ts);
+/// assert!(phrase.split(' ').count() == 4);
+/// assert!(phrase_decode_timestamp(&phrase).unwrap() == ts);
+/// ```
+pub fn phrase_encode_timestamp(ts: std::time::SystemTime) -> String {
+    let secs = ts.duration_since(std::time::UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    binary_to_phrase(&secs.to_be_bytes())
+}
+
+/// phrase_decode_timestamp is the inverse of phrase_encode_timestamp. An error is returned if the
+/// phrase does not hold exactly 8 bytes, or if the timestamp cannot be represented by SystemTime.
+///
+/// ```
+/// use mnemonic_16bit::phrase_decode_timestamp;
+/// use std::time::UNIX_EPOCH;
+///
+/// assert!(phrase_decode_timestamp("abbey0 abbey0 abbey0 abbey0").unwrap() == UNIX_EPOCH);
+/// ```
+pub fn phrase_decode_timestamp(phrase: &str) -> Result<std::time::SystemTime, Error> {
+    let data = phrase_to_binary(phrase)?;
+    let mut bytes = [0u8; 8];
+    if data.len() != bytes.len() {
+        bail!("phrase has {} bytes, but a timestamp has 8", data.len());
+    }
+    bytes.copy_from_slice(&data);
+    let secs = std::time::Duration::from_secs(u64::from_be_bytes(bytes));
+    std::time::UNIX_EPOCH.checked_add(secs).context("timestamp is out of range")
+}
+
+/// phrase_encode_uuid will convert a 16 byte UUID into a phrase. UUID phrases are always 8 words
+/// long.
+///
+/// ```
+/// use mnemonic_16bit::{phrase_decode_uuid, phrase_encode_uuid};
+///
+/// let uuid = [7u8; 16];
+/// let phrase = phrase_encode_uuid(&uuid);
+/// assert!(phrase.split(' ').count() == 8);
+/// assert!(phrase_decode_uuid(&phrase).unwrap() == uuid);
+/// ```
+pub fn phrase_encode_uuid(uuid: &[u8; 16]) -> String {
+    binary_to_phrase(uuid)
+}
+
+/// phrase_decode_uuid is the inverse of phrase_encode_uuid. An error is returned if the phrase
+/// does not hold exactly 16 bytes.
+///
+/// ```
+/// use mnemonic_16bit::phrase_decode_uuid;
+///
+/// assert!(phrase_decode_uuid("abbey0").is_err());
+/// ```
+pub fn phrase_decode_uuid(phrase: &str) -> Result<[u8; 16], Error> {
+    let data = phrase_to_binary(phrase)?;
+    let mut uuid = [0u8; 16];
+    if data.len() != uuid.len() {
+        bail!("phrase has {} bytes, but a uuid has 16", data.len());
+    }
+    uuid.copy_from_slice(&data);
+    Ok(uuid)
+}
+
+/// edit_distance returns the Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + if ca == *cb { 0 } else { 1 };
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
+/// closest_word returns the dictionary word with the smallest edit distance to word. Ties go to
+/// the word that appears first in the dictionary.
+fn closest_word(word: &str) -> &'static str {
+    DICTIONARY.iter().copied().min_by_key(|candidate| edit_distance(word, candidate)).unwrap_or(DICTIONARY[0])
+}
+
+/// check_correctable returns an error if the word is too short to be matched against the
+/// dictionary. Tokens such as "21" have no word part at all, and guessing a word for them would
+/// invent data rather than correct a typo.
+fn check_correctable(word: &str, i: usize) -> Result<(), Error> {
+    if word.chars().count() < DICTIONARY_UNIQUE_PREFIX {
+        bail!("word {} is too short to be corrected, needs at least {} letters", i, DICTIONARY_UNIQUE_PREFIX);
+    }
+    Ok(())
+}
+
+/// is_dictionary_word reports whether the word, without its numerical suffix, can be found in
+/// the dictionary.
+fn is_dictionary_word(word: &str) -> bool {
+    word.is_ascii() && index_of_word(word).is_ok()
+}
+
+/// phrase_to_binary_fuzzy_match will parse the phrase, replacing every word that is not in the
+/// dictionary with the closest dictionary word by edit distance. The numerical suffixes are kept
+/// as they are. Words shorter than the unique prefix length, including tokens that are only a
+/// number, are not guessed at and cause an error instead. Along with the data, a list of the
+/// substitutions that were made is returned, with each entry formatted as "original →
+/// substituted".
+///
+/// ```
+/// use mnemonic_16bit::{phrase_to_binary, phrase_to_binary_fuzzy_match};
+///
+/// let (data, substitutions) = phrase_to_binary_fuzzy_match("sugar21 taffee5 mob32").unwrap();
+/// assert!(data == phrase_to_binary("sugar21 toffee5 mob32").unwrap());
+/// assert!(substitutions == ["taffee → toffee"]);
+/// ```
+pub fn phrase_to_binary_fuzzy_match(phrase: &str) -> Result<(Vec<u8>, Vec<String>), Error> {
+    let mut words = Vec::new();
+    let mut substitutions = Vec::new();
+    for (i, token) in phrase_words(phrase).enumerate() {
+        let word = token.trim_end_matches(|c: char| c.is_ascii_digit());
+        let suffix = &token[word.len()..];
+        if is_dictionary_word(word) {
+            words.push(token.to_string());
+            continue;
+        }
+        check_correctable(word, i)?;
+        let replacement = closest_word(word);
+        substitutions.push(format!("{} → {}", word, replacement));
+        words.push(format!("{}{}", replacement, suffix));
+    }
+    let data = phrase_to_binary(&words.join(" "))?;
+    Ok((data, substitutions))
+}
+
+/// phrase_suggest_correction will look for a single word in the phrase that is not in the
+/// dictionary and return the phrase with that word replaced by the closest dictionary word. If
+/// the phrase is already valid, or if more than one word is invalid, no suggestion is made. An
+/// error is returned if the phrase fails to decode for a reason other than a bad word, if an
+/// invalid word is too short to be corrected (such as a token that is only a number), or if the
+/// corrected phrase still fails to decode.
+///
+/// ```
+/// use mnemonic_16bit::phrase_suggest_correction;
+///
+/// let suggestion = phrase_suggest_correction("sugar21 taffee5 mob32").unwrap();
+/// assert!(suggestion == Some("sugar21 toffee5 mob32".to_string()));
+/// assert!(phrase_suggest_correction("sugar21 toffee5 mob32").unwrap().is_none());
+/// ```
+pub fn phrase_suggest_correction(phrase: &str) -> Result<Option<String>, Error> {
+    let mut tokens: Vec<String> = phrase_words(phrase).map(|token| token.to_string()).collect();
+    let mut invalid = None;
+    for (i, token) in tokens.iter().enumerate() {
+        let word = token.trim_end_matches(|c: char| c.is_ascii_digit());
+        if is_dictionary_word(word) {
+            continue;
+        }
+        if invalid.is_some() {
+            return Ok(None);
+        }
+        invalid = Some(i);
+    }
+    let i = match invalid {
+        Some(i) => i,
+        None => {
+            phrase_to_binary(phrase)?;
+            return Ok(None);
+        }
+    };
+
+    // Only a single invalid word is corrected, so the length check is only needed for that word.
+    let word = tokens[i].trim_end_matches(|c: char| c.is_ascii_digit());
+    check_correctable(word, i)?;
+    let suffix = &tokens[i][word.len()..];
+    tokens[i] = format!("{}{}", closest_word(word), suffix);
+    let corrected = tokens.join(" ");
+    phrase_to_binary(&corrected).context("corrected phrase is still invalid")?;
+    Ok(Some(corrected))
+}
+
+/// phrase_to_binary_with_padding_validation will parse the phrase and check that the decoded data
+/// is a multiple of align bytes long, which is useful for protocols that work in fixed size
+/// blocks. An align of 0 is rejected.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase, phrase_to_binary_with_padding_validation};
+///
+/// let phrase = binary_to_phrase(&[1, 2, 3, 4]);
+/// assert!(phrase_to_binary_with_padding_validation(&phrase, 4).unwrap() == [1, 2, 3, 4]);
+/// assert!(phrase_to_binary_with_padding_validation(&phrase, 3).is_err());
+/// ```
+pub fn phrase_to_binary_with_padding_validation(phrase: &str, align: usize) -> Result<Vec<u8>, Error> {
+    if align == 0 {
+        bail!("alignment must be at least 1");
+    }
+    let data = phrase_to_binary(phrase)?;
+    if data.len() % align != 0 {
+        bail!("decoded data is not aligned: length must be a multiple of {}, got {}", align, data.len());
+    }
+    Ok(data)
+}
+
+/// binary_to_phrase_aligned will zero-pad the data to the next multiple of align bytes and then
+/// convert it to a phrase. Data that is already aligned is not padded. An align of 0 means no
+/// padding is added.
+///
+/// ```
+/// use mnemonic_16bit::{binary_to_phrase_aligned, phrase_to_binary_with_padding_validation};
+///
+/// let phrase = binary_to_phrase_aligned(&[1, 2, 3], 4);
+/// assert!(phrase_to_binary_with_padding_validation(&phrase, 4).unwrap() == [1, 2, 3, 0]);
+/// ```
+pub fn binary_to_phrase_aligned(data: &[u8], align: usize) -> String {
+    let padding = if align == 0 {
+        0
+    } else {
+        (align - data.len() % align) % align
+    };
+    encode_bytes(data.iter().copied().chain(std::iter::repeat_n(0, padding)))
+}
+
+/// Phrase holds binary data that is displayed as a mnemonic-16bit phrase. Parsing a Phrase from a
+/// string uses phrase_to_binary, and displaying a Phrase uses binary_to_phrase.
+///
+/// ```
+/// use mnemonic_16bit::Phrase;
+///
+/// let phrase: Phrase = "abbey0 ace64".parse().unwrap();
+/// assert!(phrase.as_bytes() == [0, 0, 5]);
+/// assert!(phrase.to_string() == "abbey0 ace64");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Phrase(Vec<u8>);
+
+impl Phrase {
+    /// as_bytes returns the binary data held by the phrase.
+    ///
+    /// ```
+    /// use mnemonic_16bit::Phrase;
+    ///
+    /// let phrase = Phrase::from(vec![0, 0]);
+    /// assert!(phrase.as_bytes() == [0, 0]);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// len returns the number of bytes held by the phrase.
+    ///
+    /// ```
+    /// use mnemonic_16bit::Phrase;
+    ///
+    /// let phrase: Phrase = "abbey0 ace64".parse().unwrap();
+    /// assert!(phrase.len() == 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// is_empty returns true if the phrase holds no bytes.
+    ///
+    /// ```
+    /// use mnemonic_16bit::Phrase;
+    ///
+    /// assert!(Phrase::default().is_empty());
+    /// assert!(!Phrase::from(vec![0]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// chunks returns an iterator over the phrase in chunks of chunk_bytes bytes, starting at the
+    /// beginning of the phrase. The last chunk may be shorter than chunk_bytes. Like
+    /// slice::chunks, this will panic if chunk_bytes is 0.
+    ///
+    /// ```
+    /// use mnemonic_16bit::Phrase;
+    ///
+    /// let phrase = Phrase::from(vec![0, 0, 5]);
+    /// let chunks: Vec<String> = phrase.chunks(2).map(|chunk| chunk.to_string()).collect();
+    /// assert!(chunks == ["abbey0", "ace64"]);
+    /// ```
+    pub fn chunks(&self, chunk_bytes: usize) -> impl Iterator<Item = Phrase> + '_ {
+        self.0.chunks(chunk_bytes).map(|chunk| Phrase(chunk.to_vec()))
+    }
+
+    /// chunks_exact returns an iterator over the phrase in chunks of exactly chunk_bytes bytes. If
+    /// the length of the phrase is not a multiple of chunk_bytes, the leftover bytes are skipped by
+    /// the iterator and can be retrieved with PhraseChunksExact::remainder. Like
+    /// slice::chunks_exact, this will panic if chunk_bytes is 0.
+    ///
+    /// ```
+    /// use mnemonic_16bit::Phrase;
+    ///
+    /// let phrase = Phrase::from(vec![0, 0, 5]);
+    /// let chunks = phrase.chunks_exact(2);
+    /// assert!(chunks.remainder().to_string() == "ace64");
+    /// assert!(chunks.map(|chunk| chunk.to_string()).collect::<Vec<_>>() == ["abbey0"]);
+    /// ```
+    pub fn chunks_exact(&self, chunk_bytes: usize) -> PhraseChunksExact<'_> {
+        PhraseChunksExact(self.0.chunks_exact(chunk_bytes))
+    }
+
+    /// xor returns a new Phrase holding the byte-wise xor of the two phrases. An error is returned
+    /// if the phrases have different lengths.
+    ///
+    /// ```
+    /// use mnemonic_16bit::Phrase;
+    ///
+    /// let a = Phrase::from(vec![1, 2]);
+    /// let b = Phrase::from(vec![3, 2]);
+    /// assert!(a.xor(&b).unwrap().as_bytes() == [2, 0]);
+    /// assert!(a.xor(&Phrase::from(vec![1])).is_err());
+    /// ```
+    pub fn xor(&self, other: &Phrase) -> Result<Phrase, Error> {
+        if self.len() != other.len() {
+            bail!("phrases have different lengths: {} bytes and {} bytes", self.len(), other.len());
+        }
+        Ok(self.0.iter().zip(other.0.iter()).map(|(a, b)| a ^ b).collect())
+    }
+
+    /// concat returns a new Phrase holding the bytes of this phrase followed by the bytes of
+    /// other. This works on the bytes rather than the phrase strings, so it is unaffected by a
+    /// final word that only holds one byte.
+    ///
+    /// ```
+    /// use mnemonic_16bit::Phrase;
+    ///
+    /// let a = Phrase::from(vec![0]);
+    /// let b = Phrase::from(vec![0, 0]);
+    /// assert!(a.concat(&b).to_string() == "abbey0 abbey64");
+    /// ```
+    pub fn concat(&self, other: &Phrase) -> Phrase {
+        Phrase([self.as_bytes(), other.as_bytes()].concat())
+    }
+
+    /// slice returns a new Phrase holding the bytes in the range start..end. An error is returned
+    /// if start is greater than end or end is greater than the length of the phrase.
+    ///
+    /// ```
+    /// use mnemonic_16bit::Phrase;
+    ///
+    /// let phrase = Phrase::from(vec![1, 2, 3]);
+    /// assert!(phrase.slice(1, 3).unwrap().as_bytes() == [2, 3]);
+    /// assert!(phrase.slice(2, 4).is_err());
+    /// ```
+    pub fn slice(&self, start: usize, end: usize) -> Result<Phrase, Error> {
+        if start > end || end > self.len() {
+            bail!("range {}..{} is out of bounds for a phrase with {} bytes", start, end, self.len());
+        }
+        Ok(Phrase(self.0[start..end].to_vec()))
+    }
+
+    /// reverse returns a new Phrase holding the bytes of this phrase in reverse order.
+    ///
+    /// ```
+    /// use mnemonic_16bit::Phrase;
+    ///
+    /// let phrase = Phrase::from(vec![1, 2, 3]);
+    /// assert!(phrase.reverse().as_bytes() == [3, 2, 1]);
+    /// ```
+    pub fn reverse(&self) -> Phrase {
+        Phrase(self.0.iter().copied().rev().collect())
+    }
+
+    /// rotate_left returns a new Phrase with the bytes rotated n places to the left, so the byte
+    /// at index n becomes the first byte. Rotating an empty phrase returns an empty phrase.
+    ///
+    /// ```
+    /// use mnemonic_16bit::Phrase;
+    ///
+    /// let phrase = Phrase::from(vec![1, 2, 3]);
+    /// assert!(phrase.rotate_left(1).as_bytes() == [2, 3, 1]);
+    /// ```
+    pub fn rotate_left(&self, n: usize) -> Phrase {
+        let mut data = self.0.clone();
+        if !data.is_empty() {
+            let n = n % data.len();
+            data.rotate_left(n);
+        }
+        Phrase(data)
+    }
+
+    /// rotate_right returns a new Phrase with the bytes rotated n places to the right, so the last
+    /// n bytes move to the front. Rotating an empty phrase returns an empty phrase.
+    ///
+    /// ```
+    /// use mnemonic_16bit::Phrase;
+    ///
+    /// let phrase = Phrase::from(vec![1, 2, 3]);
+    /// assert!(phrase.rotate_right(1).as_bytes() == [3, 1, 2]);
+    /// ```
+    pub fn rotate_right(&self, n: usize) -> Phrase {
+        let mut data = self.0.clone();
+        if !data.is_empty() {
+            let n = n % data.len();
+            data.rotate_right(n);
+        }
+        Phrase(data)
+    }
+
+    /// to_hex_string returns the bytes of the phrase as a lowercase hex string.
+    ///
+    /// ```
+    /// use mnemonic_16bit::Phrase;
+    ///
+    /// let phrase = Phrase::from(vec![0x0a, 0xff]);
+    /// assert!(phrase.to_hex_string() == "0aff");
+    /// ```
+    pub fn to_hex_string(&self) -> String {
+        self.0.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    /// from_hex creates a Phrase from a hex string, which may optionally start with "0x". Both
+    /// uppercase and lowercase digits are accepted.
+    ///
+    /// ```
+    /// use mnemonic_16bit::Phrase;
+    ///
+    /// let phrase = Phrase::from_hex("0x0aFF").unwrap();
+    /// assert!(phrase.as_bytes() == [0x0a, 0xff]);
+    /// assert!(Phrase::from_hex("0af").is_err());
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Phrase, Error> {
+        let hex = hex.strip_prefix("0x").unwrap_or(hex);
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            bail!("invalid hex: string contains non-hex characters");
+        }
+        if hex.len() % 2 != 0 {
+            bail!("invalid hex: string has an odd number of digits");
+        }
+
+        let mut data = Vec::with_capacity(hex.len() / 2);
+        for i in (0..hex.len()).step_by(2) {
+            data.push(u8::from_str_radix(&hex[i..i+2], 16).context("invalid hex")?);
+        }
+        Ok(Phrase(data))
+    }
+
+    /// from_base64 creates a Phrase from a standard Base64 string with padding.
+    ///
+    /// ```
+    /// use mnemonic_16bit::Phrase;
+    ///
+    /// let phrase = Phrase::from_base64("AAAF").unwrap();
+    /// assert!(phrase.as_bytes() == [0, 0, 5]);
+    /// assert!(Phrase::from_base64("AAA").is_err());
+    /// ```
+    #[cfg(feature = "base64")]
+    pub fn from_base64(b64: &str) -> Result<Phrase, Error> {
+        use base64::Engine;
+        let data = base64::engine::general_purpose::STANDARD.decode(b64).context("invalid base64")?;
+        Ok(Phrase(data))
+    }
+
+    /// to_base64 returns the bytes of the phrase as a standard Base64 string with padding.
+    ///
+    /// ```
+    /// use mnemonic_16bit::Phrase;
+    ///
+    /// let phrase = Phrase::from(vec![0, 0, 5]);
+    /// assert!(phrase.to_base64() == "AAAF");
+    /// ```
+    #[cfg(feature = "base64")]
+    pub fn to_base64(&self) -> String {
+        use base64::Engine;
+        base64::engine::general_purpose::STANDARD.encode(&self.0)
+    }
+}
+
+impl From<Vec<u8>> for Phrase {
+    fn from(data: Vec<u8>) -> Phrase {
+        Phrase(data)
+    }
+}
+
+impl FromStr for Phrase {
+    type Err = Error;
+
+    fn from_str(phrase: &str) -> Result<Phrase, Error> {
+        Ok(Phrase(phrase_to_binary(phrase)?))
+    }
+}
+
+impl fmt::Display for Phrase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&binary_to_phrase(&self.0))
+    }
+}
+
+impl IntoIterator for Phrase {
+    type Item = u8;
+    type IntoIter = std::vec::IntoIter<u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Phrase {
+    type Item = &'a u8;
+    type IntoIter = std::slice::Iter<'a, u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl Extend<u8> for Phrase {
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
+impl FromIterator<u8> for Phrase {
+    fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Phrase {
+        let bytes: Vec<u8> = iter.into_iter().collect();
+        Phrase(bytes)
+    }
+}
+
+/// PhraseChunksExact is an iterator over a Phrase in chunks of an exact size. It is created by
+/// Phrase::chunks_exact.
+///
+/// ```
+/// use mnemonic_16bit::Phrase;
+///
+/// let phrase = Phrase::from(vec![1, 2, 3]);
+/// assert!(phrase.chunks_exact(2).count() == 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PhraseChunksExact<'a>(std::slice::ChunksExact<'a, u8>);
+
+impl PhraseChunksExact<'_> {
+    /// remainder returns the bytes at the end of the phrase that did not fit into a full chunk.
+    ///
+    /// ```
+    /// use mnemonic_16bit::Phrase;
+    ///
+    /// let phrase = Phrase::from(vec![1, 2, 3]);
+    /// assert!(phrase.chunks_exact(2).remainder().as_bytes() == [3]);
+    /// ```
+    pub fn remainder(&self) -> Phrase {
+        Phrase(self.0.remainder().to_vec())
+    }
+}
+
+impl Iterator for PhraseChunksExact<'_> {
+    type Item = Phrase;
+
+    fn next(&mut self) -> Option<Phrase> {
+        self.0.next().map(|chunk| Phrase(chunk.to_vec()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl ExactSizeIterator for PhraseChunksExact<'_> {}
+
+/// v1 contains the version 1 phrase format, which is the format described at the top of this
+/// crate. Every public function and type that encodes or decodes phrases is re-exported here,
+/// including those behind cargo features. Callers that need a specific format version should
+/// import from here, so their code keeps working unchanged if a later format version is added.
+///
+/// ```
+/// use mnemonic_16bit::v1::{binary_to_phrase, phrase_to_binary, phrase_to_binary_with_positions};
+///
+/// let phrase = binary_to_phrase(&[0, 0]);
+/// assert!(phrase_to_binary(&phrase).unwrap() == [0, 0]);
+/// assert!(phrase_to_binary_with_positions("1:abbey0").unwrap() == [0, 0]);
+/// ```
+pub mod v1 {
+    pub use crate::{
+        binary_to_phrase, phrase_to_binary, binary_to_phrase_with_positions,
+        phrase_to_binary_with_positions, phrase_contains_word, phrase_word_positions,
+        phrase_is_palindrome, phrase_popcount, binary_to_phrase_with_entropy_check,
+        binary_to_phrase_with_magic, phrase_to_binary_with_magic, phrase_delta_encode,
+        phrase_delta_decode, binary_to_phrase_structured, phrase_from_structured,
+        phrase_to_binary_checked_prefix, phrase_to_binary_strip_prefix,
+        binary_to_phrase_with_suffix_bytes, phrase_to_binary_strip_suffix, phrase_embed_bit,
+        phrase_extract_bit, binary_to_phrase_with_salt, phrase_to_binary_strip_salt, phrase_mask,
+        phrase_xor_key_stream, binary_to_phrase_zero_padded, binary_to_phrase_zero_stripped,
+        phrase_to_binary_zero_padded, phrase_to_binary_max_words, phrase_to_binary_exact_words,
+        phrase_first_word, phrase_last_word, phrase_has_final_byte, phrase_is_even_bytes,
+        phrase_byte_at, binary_to_phrase_words_only, binary_to_phrase_suffixes_only,
+        phrase_to_binary_from_words_and_suffixes, phrase_count_zeroes, phrase_run_length,
+        phrase_monotone_score, binary_to_phrase_chunks_labeled, phrase_chunks_labeled_decode,
+        parse_phrase_allow_no_suffix, binary_to_phrase_alpha_suffix,
+        phrase_to_binary_alpha_suffix, dict_unique_prefix_length, phrase_to_binary_words_only,
+        phrase_to_binary_from_parts, phrase_is_prefix_of, phrase_common_prefix,
+        phrase_to_binary_or_default, binary_to_phrase_checked_no_repetition, phrase_byte_histogram,
+        phrase_from_usize, phrase_to_usize, phrase_encode_timestamp, phrase_decode_timestamp,
+        phrase_encode_uuid, phrase_decode_uuid, phrase_to_binary_fuzzy_match,
+        phrase_suggest_correction, phrase_to_binary_with_padding_validation,
+        binary_to_phrase_aligned, Phrase, PhraseChunksExact,
+    };
+    #[cfg(feature = "cache")]
+    pub use crate::{phrase_to_binary_cache, set_phrase_cache_capacity, clear_phrase_cache};
+    #[cfg(feature = "hmac")]
+    pub use crate::{binary_to_phrase_with_hmac, phrase_to_binary_with_hmac_verify};
+    #[cfg(feature = "log")]
+    pub use crate::phrase_to_binary_log_errors;
+    #[cfg(feature = "serde_json")]
+    pub use crate::{binary_to_phrase_json, phrase_from_json, phrase_to_binary_from_json_string};
+    #[cfg(feature = "toml")]
+    pub use crate::{binary_to_phrase_toml_line, phrase_from_toml_line};
+    #[cfg(feature = "tracing")]
+    pub use crate::{phrase_to_binary_with_span, binary_to_phrase_instrumented};
+    #[cfg(feature = "zeroize")]
+    pub use crate::phrase_to_binary_and_zero;
+}
+
+/// prelude re-exports the latest version of the phrase format. It currently points at v1.
+///
+/// ```
+/// use mnemonic_16bit::prelude::*;
+///
+/// let phrase: Phrase = binary_to_phrase(&[0, 0]).parse().unwrap();
+/// assert!(phrase.as_bytes() == [0, 0]);
+/// ```
+pub mod prelude {
+    pub use crate::v1::*;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use userspace_rng::Csprng;
+    use rand_core::RngCore;
+
+    #[test]
+    // Try a bunch of binary arrays and see that they all correctly convert into phrases and then
+    // back into the same binary.
+    fn check_seed_phrases() {
+        // Try empty array.
+        let basic = [0u8; 0];
+        let phrase = binary_to_phrase(&basic);
+        let result = phrase_to_binary(&phrase).unwrap();
+        assert!(basic[..] == result[..]);
+
+        // Try all possible 1 byte values.
+        for i in 0..=255 {
+            let basic = [i as u8; 1];
+            let phrase = binary_to_phrase(&basic);
+            let result = phrase_to_binary(&phrase).unwrap();
+            assert!(basic[..] == result[..]);
+        }
+
+        // Try zero values for all possible array sizes 0-255.
+        for i in 0..=255 {
+            let basic = vec![0u8; i];
+            let phrase = binary_to_phrase(&basic);
+            let result = phrase_to_binary(&phrase).unwrap();
+            assert!(basic[..] == result[..]);
+        }
+
+        // Try random data for all array sizes 0-255, 8 variations each size.
+        let mut rng = Csprng {};
+        for _ in 0..8 {
+            for i in 0..=255 {
+                let mut basic = vec![0u8; i];
+                rng.fill_bytes(&mut basic);
+                let phrase = binary_to_phrase(&basic);
+                let result = phrase_to_binary(&phrase).unwrap();
+                assert!(basic[..] == result[..]);
+            }
+        }
+
+        // Try all possible 2 byte values.
+        for i in 0..=255 {
+            for j in 0..=255 {
+                let mut basic = [0u8; 2];
+                basic[0] = i;
+                basic[1] = j;
+                let phrase = binary_to_phrase(&basic);
+                let result = phrase_to_binary(&phrase).unwrap();
+                assert!(basic[..] == result[..]);
+            }
+        }
+    }
+
+    #[test]
+    // Check a variety of invalid phrases.
+    fn check_bad_phrases() {
+        phrase_to_binary("a").unwrap_err();
+        phrase_to_binary("a64").unwrap_err();
+        phrase_to_binary("abbey").unwrap_err();
+        phrase_to_binary("abbey65").unwrap_err();
+        phrase_to_binary("yacht64").unwrap_err();
+        phrase_to_binary("sugar21 ab55 mob32").unwrap_err();
+        phrase_to_binary("sugar21 toffee mob32").unwrap_err();
+
+        // This one should work even though we trucated the words.
+        phrase_to_binary("sug21 tof21 mob32").unwrap();
+    }
+
+    #[test]
+    // Check that phrases with position prefixes round trip, and that bad prefixes are rejected.
+    fn check_positions() {
+        for i in 0..=32 {
+            let basic = vec![7u8; i];
+            let phrase = binary_to_phrase_with_positions(&basic);
+            let result = phrase_to_binary_with_positions(&phrase).unwrap();
+            assert!(basic[..] == result[..]);
+        }
+
+        assert!(binary_to_phrase_with_positions(&[0, 0, 0]) == "1:abbey0 2:abbey64");
+        phrase_to_binary_with_positions("1:abbey0 2:abbey64").unwrap();
+        phrase_to_binary_with_positions("abbey0 2:abbey64").unwrap_err();
+        phrase_to_binary_with_positions("1:abbey0 3:abbey64").unwrap_err();
+        phrase_to_binary_with_positions("2:abbey0 1:abbey64").unwrap_err();
+        phrase_to_binary_with_positions("x:abbey0").unwrap_err();
+        phrase_to_binary_with_positions("1:abbey").unwrap_err();
+    }
+
+    #[test]
+    // Check that a Phrase can be parsed, displayed, and iterated over.
+    fn check_phrase_iter() {
+        let phrase: Phrase = "sugar21 mob32".parse().unwrap();
+        assert!(phrase.to_string() == "sugar21 mob32");
+
+        let mut borrowed = Vec::new();
+        for byte in &phrase {
+            borrowed.push(*byte);
+        }
+        let mut owned = Vec::new();
+        for byte in phrase.clone() {
+            owned.push(byte);
+        }
+        assert!(borrowed == phrase.as_bytes());
+        assert!(owned == phrase.as_bytes());
+        assert!(owned.len() == 4);
+
+        "sugar21 toffee mob32".parse::<Phrase>().unwrap_err();
+    }
+
+    #[test]
+    // Check that bytes can be accumulated into a Phrase.
+    fn check_phrase_extend() {
+        let mut phrase = Phrase::default();
+        phrase.extend(vec![0u8; 2]);
+        assert!(phrase.to_string() == "abbey0");
+        phrase.extend([0u8; 1]);
+        assert!(phrase.to_string() == "abbey0 abbey64");
+        assert!(phrase.len() == 3);
+    }
+
+    #[test]
+    // Check that bytes can be collected into a Phrase.
+    fn check_phrase_collect() {
+        let phrase: Phrase = (0u8..5).collect();
+        assert!(phrase.as_bytes() == [0, 1, 2, 3, 4]);
+        let result = phrase_to_binary(&phrase.to_string()).unwrap();
+        assert!(result == phrase.as_bytes());
+
+        let empty: Phrase = std::iter::empty().collect();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    // Check searching for words and tokens within a phrase.
+    fn check_contains_word() {
+        let phrase = "sugar21 toffee5 mob32";
+        assert!(phrase_contains_word(phrase, "toffee").unwrap());
+        assert!(phrase_contains_word(phrase, "toffee5").unwrap());
+        assert!(!phrase_contains_word(phrase, "toffee6").unwrap());
+        assert!(!phrase_contains_word(phrase, "abbey").unwrap());
+        assert!(!phrase_contains_word("", "abbey").unwrap());
+        phrase_contains_word("sugar21 toffee mob32", "mob").unwrap_err();
+
+        // Words are matched on their prefix, the same way the decoder reads them.
+        assert!(phrase_contains_word("sug21 mob32", "sugar").unwrap());
+        assert!(phrase_contains_word("sug21 mob32", "sugar21").unwrap());
+        assert!(phrase_contains_word("sugary21 mob32", "sugar21").unwrap());
+        assert!(phrase_contains_word("sugar21 mob32", "sug").unwrap());
+        assert!(phrase_contains_word("sugar21 mob32", "sugary21").unwrap());
+        assert!(!phrase_contains_word("sugary21 mob32", "sugar22").unwrap());
+        assert!(phrase_contains_word("sugar5 mob32", "sugar05").unwrap());
+        assert!(!phrase_contains_word("sugar21 mob32", "zzz").unwrap());
+        assert!(!phrase_contains_word("sugar21 mob32", "su").unwrap());
+        assert!(!phrase_contains_word("sugar21 mob32", "aaé").unwrap());
+    }
+
+    #[test]
+    // Check finding the positions of words within a phrase.
+    fn check_word_positions() {
+        let phrase = "mob1 sugar21 mob32 mob1";
+        assert!(phrase_word_positions(phrase, "mob").unwrap() == [0, 2, 3]);
+        assert!(phrase_word_positions(phrase, "mob1").unwrap() == [0, 3]);
+        assert!(phrase_word_positions(phrase, "sugar21").unwrap() == [1]);
+        assert!(phrase_word_positions(phrase, "abbey").unwrap().is_empty());
+        phrase_word_positions("mob1 mob", "mob").unwrap_err();
+
+        // Words are matched on their prefix, the same way the decoder reads them.
+        assert!(phrase_word_positions("sug21 sugar21", "sugar").unwrap() == [0, 1]);
+        assert!(phrase_word_positions("sug21 sugary21 sugar22", "sugar21").unwrap() == [0, 1]);
+        assert!(phrase_word_positions("sug21 sugary21 sugar22", "sug").unwrap() == [0, 1, 2]);
+        assert!(phrase_word_positions("sug21 sugary21", "zzz").unwrap().is_empty());
+    }
+
+    #[test]
+    // Check palindrome detection on the decoded bytes. Note that "abbey0" decodes to [0, 0], which
+    // is a palindrome at the byte level even though the phrase itself is not.
+    fn check_palindrome() {
+        assert!(phrase_is_palindrome("abbey0").unwrap());
+        assert!(phrase_is_palindrome("").unwrap());
+        assert!(phrase_is_palindrome(&binary_to_phrase(&[1, 2, 3, 2, 1])).unwrap());
+        assert!(phrase_is_palindrome(&binary_to_phrase(&[1, 2, 2, 1])).unwrap());
+        assert!(!phrase_is_palindrome(&binary_to_phrase(&[1, 2, 3])).unwrap());
+        phrase_is_palindrome("abbey").unwrap_err();
+    }
+
+    #[test]
+    // Check the hamming weight of decoded phrases.
+    fn check_popcount() {
+        assert!(phrase_popcount("").unwrap() == 0);
+        assert!(phrase_popcount("abbey0").unwrap() == 0);
+        assert!(phrase_popcount(&binary_to_phrase(&[255, 255, 255])).unwrap() == 24);
+        assert!(phrase_popcount(&binary_to_phrase(&[1, 3, 7])).unwrap() == 6);
+        phrase_popcount("abbey").unwrap_err();
+    }
+
+    #[test]
+    // Check that low entropy data is rejected by the entropy check.
+    fn check_entropy_check() {
+        binary_to_phrase_with_entropy_check(&[0u8; 32], 1).unwrap_err();
+        let phrase = binary_to_phrase_with_entropy_check(&[0u8; 32], 0).unwrap();
+        assert!(phrase == binary_to_phrase(&[0u8; 32]));
+
+        // Sequential and repeating patterns are rejected even though their byte values are evenly
+        // spread.
+        let data: Vec<u8> = (0..=255).collect();
+        binary_to_phrase_with_entropy_check(&data, 64).unwrap_err();
+        let data: Vec<u8> = (0..16).map(|i| i * 7).collect();
+        binary_to_phrase_with_entropy_check(&data, 32).unwrap_err();
+        let data: Vec<u8> = (0..16).map(|i| i % 2).collect();
+        binary_to_phrase_with_entropy_check(&data, 32).unwrap_err();
+
+        // Short random keys are capped at len * log2(len) bits.
+        let mut rng = Csprng {};
+        for _ in 0..100 {
+            let mut data = [0u8; 16];
+            rng.fill_bytes(&mut data);
+            let estimated = estimate_entropy_bits(&data);
+            assert!((48..=64).contains(&estimated));
+        }
+
+        // Long random inputs come close to 8 bits per byte.
+        let mut data = vec![0u8; 4096];
+        rng.fill_bytes(&mut data);
+        binary_to_phrase_with_entropy_check(&data, 4096 * 79 / 10).unwrap();
+    }
+
+    #[test]
+    // Check encoding and decoding with magic bytes.
+    fn check_magic() {
+        let magic = [0xde, 0xad];
+        for i in 0..=16 {
+            let basic = vec![3u8; i];
+            let phrase = binary_to_phrase_with_magic(&basic, &magic);
+            let result = phrase_to_binary_with_magic(&phrase, &magic).unwrap();
+            assert!(basic[..] == result[..]);
+            phrase_to_binary_with_magic(&phrase, &[0xde, 0xae]).unwrap_err();
+        }
+        phrase_to_binary_with_magic("", &magic).unwrap_err();
+        phrase_to_binary_with_magic(&binary_to_phrase(&[0xde]), &magic).unwrap_err();
+    }
+
+    #[test]
+    // Check delta encoding between two phrases.
+    fn check_delta_encode() {
+        let prev = binary_to_phrase(&[1, 2, 3]);
+        let curr = binary_to_phrase(&[1, 2, 7]);
+        let delta = phrase_delta_encode(&prev, &curr).unwrap();
+        assert!(phrase_to_binary(&delta).unwrap() == [0, 0, 4]);
+        assert!(phrase_delta_encode(&prev, &prev).unwrap() == binary_to_phrase(&[0u8; 3]));
+        phrase_delta_encode(&prev, &binary_to_phrase(&[1, 2])).unwrap_err();
+        phrase_delta_encode(&prev, "abbey").unwrap_err();
+    }
+
+    #[test]
+    // Check that delta decoding recovers the original phrase.
+    fn check_delta_decode() {
+        let mut rng = Csprng {};
+        for i in 0..=32 {
+            let mut prev = vec![0u8; i];
+            let mut curr = vec![0u8; i];
+            rng.fill_bytes(&mut prev);
+            rng.fill_bytes(&mut curr);
+            let prev = binary_to_phrase(&prev);
+            let curr = binary_to_phrase(&curr);
+            let delta = phrase_delta_encode(&prev, &curr).unwrap();
+            assert!(phrase_delta_decode(&prev, &delta).unwrap() == curr);
+        }
+        phrase_delta_decode("abbey0", "abbey64").unwrap_err();
+    }
+
+    #[test]
+    // Check that every word produced by the encoder is a full word from the dictionary.
+    fn check_words_in_dictionary() {
+        for i in 0..=255 {
+            for j in 0..=255 {
+                let phrase = binary_to_phrase(&[i, j]);
+                let word = phrase.trim_end_matches(|c: char| c.is_ascii_digit());
+                assert!(DICTIONARY.contains(&word), "word {} is not in the dictionary", word);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde_json")]
+    // Check the JSON envelope produced for a phrase.
+    fn check_phrase_json() {
+        let value = binary_to_phrase_json(&[0, 0, 0]);
+        assert!(value["phrase"] == "abbey0 abbey64");
+        assert!(value["word_count"] == 2);
+        assert!(value["byte_count"] == 3);
+
+        let value = binary_to_phrase_json(&[]);
+        assert!(value["phrase"] == "");
+        assert!(value["word_count"] == 0);
+        assert!(value["byte_count"] == 0);
+    }
+
+    #[test]
+    #[cfg(feature = "serde_json")]
+    // Check decoding phrases from JSON objects, including metadata validation.
+    fn check_phrase_from_json() {
+        for i in 0..=16 {
+            let basic = vec![9u8; i];
+            let result = phrase_from_json(&binary_to_phrase_json(&basic)).unwrap();
+            assert!(basic[..] == result[..]);
+        }
+
+        let data = phrase_from_json(&serde_json::json!({"phrase": "abbey0"})).unwrap();
+        assert!(data == [0, 0]);
+        phrase_from_json(&serde_json::json!({"phrase": "abbey0", "word_count": 2})).unwrap_err();
+        phrase_from_json(&serde_json::json!({"phrase": "abbey0", "byte_count": 3})).unwrap_err();
+        phrase_from_json(&serde_json::json!({"phrase": "abbey0", "byte_count": "2"})).unwrap_err();
+        phrase_from_json(&serde_json::json!({"phrase": 5})).unwrap_err();
+        phrase_from_json(&serde_json::json!({})).unwrap_err();
+        phrase_from_json(&serde_json::json!({"phrase": "abbey"})).unwrap_err();
+    }
+
+    #[test]
+    #[cfg(feature = "serde_json")]
+    // Check decoding phrases directly from JSON strings.
+    fn check_phrase_from_json_string() {
+        let json = binary_to_phrase_json(&[1, 2, 3]).to_string();
+        assert!(phrase_to_binary_from_json_string(&json).unwrap() == [1, 2, 3]);
+        assert!(phrase_to_binary_from_json_string(r#"{"phrase": "abbey0"}"#).unwrap() == [0, 0]);
+        phrase_to_binary_from_json_string(r#"{"phrase": "abbey0""#).unwrap_err();
+        phrase_to_binary_from_json_string(r#"{"phrase": "abbey0", "byte_count": 1}"#).unwrap_err();
+    }
+
+    #[test]
+    #[cfg(feature = "toml")]
+    // Check encoding and decoding phrases as TOML lines.
+    fn check_phrase_toml_line() {
+        assert!(binary_to_phrase_toml_line(&[0, 0, 0], "phrase") == r#"phrase = "abbey0 abbey64""#);
+        assert!(binary_to_phrase_toml_line(&[0, 0], "my key") == r#""my key" = "abbey0""#);
+        for i in 0..=16 {
+            let basic = vec![200u8; i];
+            let line = binary_to_phrase_toml_line(&basic, "seed");
+            let result = phrase_from_toml_line(&line).unwrap();
+            assert!(basic[..] == result[..]);
+        }
+        phrase_from_toml_line("phrase = 5").unwrap_err();
+        phrase_from_toml_line("phrase = \"abbey0").unwrap_err();
+        phrase_from_toml_line("a = \"abbey0\"\nb = \"abbey0\"").unwrap_err();
+        phrase_from_toml_line("phrase = \"abbey\"").unwrap_err();
+    }
+
+    #[test]
+    // Check splitting a Phrase into chunks.
+    fn check_phrase_chunks() {
+        let phrase: Phrase = (0u8..7).collect();
+        let chunks: Vec<Phrase> = phrase.chunks(3).collect();
+        assert!(chunks.len() == 3);
+        assert!(chunks[0].as_bytes() == [0, 1, 2]);
+        assert!(chunks[1].as_bytes() == [3, 4, 5]);
+        assert!(chunks[2].as_bytes() == [6]);
+        assert!(Phrase::default().chunks(3).next().is_none());
+    }
+
+    #[test]
+    // Check splitting a Phrase into exact chunks with a remainder.
+    fn check_phrase_chunks_exact() {
+        let phrase: Phrase = (0u8..7).collect();
+        let mut chunks = phrase.chunks_exact(3);
+        assert!(chunks.len() == 2);
+        assert!(chunks.remainder().as_bytes() == [6]);
+        assert!(chunks.next().unwrap().as_bytes() == [0, 1, 2]);
+        assert!(chunks.next().unwrap().as_bytes() == [3, 4, 5]);
+        assert!(chunks.next().is_none());
+
+        let phrase: Phrase = (0u8..6).collect();
+        assert!(phrase.chunks_exact(2).count() == 3);
+        assert!(phrase.chunks_exact(2).remainder().is_empty());
+    }
+
+    #[test]
+    // Check that the structured output matches the phrase string.
+    fn check_structured() {
+        assert!(binary_to_phrase_structured(&[]).is_empty());
+        assert!(binary_to_phrase_structured(&[0, 0, 0]) == [("abbey".to_string(), 0), ("abbey".to_string(), 64)]);
+
+        let mut rng = Csprng {};
+        for i in 0..=32 {
+            let mut basic = vec![0u8; i];
+            rng.fill_bytes(&mut basic);
+            let words: Vec<String> = binary_to_phrase_structured(&basic)
+                .iter()
+                .map(|(word, suffix)| format!("{}{}", word, suffix))
+                .collect();
+            assert!(words.join(" ") == binary_to_phrase(&basic));
+        }
+    }
+
+    #[test]
+    // Check that structured phrases can be converted back into phrase strings.
+    fn check_from_structured() {
+        let mut rng = Csprng {};
+        for i in 0..=32 {
+            let mut basic = vec![0u8; i];
+            rng.fill_bytes(&mut basic);
+            let phrase = phrase_from_structured(&binary_to_phrase_structured(&basic)).unwrap();
+            assert!(phrase == binary_to_phrase(&basic));
+        }
+
+        assert!(phrase_from_structured(&[("sug", 21), ("ace", 64)]).unwrap() == "sug21 ace64");
+        phrase_from_structured(&[("abbey", 65)]).unwrap_err();
+        phrase_from_structured(&[("abbey", 64), ("abbey", 0)]).unwrap_err();
+        phrase_from_structured(&[("yacht", 64)]).unwrap_err();
+        phrase_from_structured(&[("zzz", 0)]).unwrap_err();
+        phrase_from_structured(&[("abbey0 abbey", 0)]).unwrap_err();
+        phrase_from_structured(&[("ab1", 0)]).unwrap_err();
+    }
+
+    #[test]
+    // Check decoding with an expected prefix.
+    fn check_checked_prefix() {
+        let phrase = binary_to_phrase(&[1, 2, 3, 4]);
+        assert!(phrase_to_binary_checked_prefix(&phrase, &[]).unwrap() == [1, 2, 3, 4]);
+        assert!(phrase_to_binary_checked_prefix(&phrase, &[1, 2]).unwrap() == [3, 4]);
+        assert!(phrase_to_binary_checked_prefix(&phrase, &[1, 2, 3, 4]).unwrap().is_empty());
+        phrase_to_binary_checked_prefix(&phrase, &[1, 3]).unwrap_err();
+        phrase_to_binary_checked_prefix(&phrase, &[1, 2, 3, 4, 5]).unwrap_err();
+        phrase_to_binary_checked_prefix("abbey", &[]).unwrap_err();
+    }
+
+    #[test]
+    // Check stripping a prefix of unknown value.
+    fn check_strip_prefix() {
+        let phrase = binary_to_phrase(&[1, 2, 3]);
+        assert!(phrase_to_binary_strip_prefix(&phrase, 0).unwrap() == [1, 2, 3]);
+        assert!(phrase_to_binary_strip_prefix(&phrase, 2).unwrap() == [3]);
+        assert!(phrase_to_binary_strip_prefix(&phrase, 3).unwrap().is_empty());
+        phrase_to_binary_strip_prefix(&phrase, 4).unwrap_err();
+        phrase_to_binary_strip_prefix("abbey", 0).unwrap_err();
+    }
+
+    #[test]
+    // Check that appending suffix bytes matches encoding the concatenated data.
+    fn check_suffix_bytes() {
+        let mut rng = Csprng {};
+        for i in 0..=8 {
+            for j in 0..=8 {
+                let mut data = vec![0u8; i];
+                let mut suffix = vec![0u8; j];
+                rng.fill_bytes(&mut data);
+                rng.fill_bytes(&mut suffix);
+                let phrase = binary_to_phrase_with_suffix_bytes(&data, &suffix);
+                assert!(phrase == binary_to_phrase(&[data, suffix].concat()));
+            }
+        }
+    }
+
+    #[test]
+    // Check stripping a suffix of unknown value.
+    fn check_strip_suffix() {
+        let phrase = binary_to_phrase(&[1, 2, 3]);
+        assert!(phrase_to_binary_strip_suffix(&phrase, 0).unwrap() == [1, 2, 3]);
+        assert!(phrase_to_binary_strip_suffix(&phrase, 2).unwrap() == [1]);
+        assert!(phrase_to_binary_strip_suffix(&phrase, 3).unwrap().is_empty());
+        phrase_to_binary_strip_suffix(&phrase, 4).unwrap_err();
+        phrase_to_binary_strip_suffix("abbey", 0).unwrap_err();
+    }
+
+    #[test]
+    // Check setting and clearing individual bits of a phrase.
+    fn check_embed_bit() {
+        let phrase = binary_to_phrase(&[0, 0, 0]);
+        let phrase = phrase_embed_bit(&phrase, 0, true).unwrap();
+        assert!(phrase_to_binary(&phrase).unwrap() == [0x80, 0, 0]);
+        let phrase = phrase_embed_bit(&phrase, 23, true).unwrap();
+        assert!(phrase_to_binary(&phrase).unwrap() == [0x80, 0, 0x01]);
+        let phrase = phrase_embed_bit(&phrase, 23, true).unwrap();
+        assert!(phrase_to_binary(&phrase).unwrap() == [0x80, 0, 0x01]);
+        let phrase = phrase_embed_bit(&phrase, 0, false).unwrap();
+        assert!(phrase_to_binary(&phrase).unwrap() == [0, 0, 0x01]);
+        phrase_embed_bit(&phrase, 24, true).unwrap_err();
+        phrase_embed_bit("", 0, true).unwrap_err();
+    }
+
+    #[test]
+    // Check that extracted bits match the bits that were embedded.
+    fn check_extract_bit() {
+        let mut phrase = binary_to_phrase(&[0u8; 5]);
+        for i in (0..40).step_by(3) {
+            phrase = phrase_embed_bit(&phrase, i, true).unwrap();
+        }
+        for i in 0..40 {
+            assert!(phrase_extract_bit(&phrase, i).unwrap() == (i % 3 == 0));
+        }
+        phrase_extract_bit(&phrase, 40).unwrap_err();
+        phrase_extract_bit("abbey", 0).unwrap_err();
+    }
+
+    #[test]
+    // Check that different salts produce different phrases for the same data.
+    fn check_salt() {
+        let data = [9u8; 6];
+        let a = binary_to_phrase_with_salt(&data, &[1, 2, 3, 4]);
+        let b = binary_to_phrase_with_salt(&data, &[1, 2, 3, 5]);
+        assert!(a != b);
+        assert!(phrase_to_binary_checked_prefix(&a, &[1, 2, 3, 4]).unwrap() == data);
+        assert!(phrase_to_binary_checked_prefix(&b, &[1, 2, 3, 5]).unwrap() == data);
+    }
+
+    #[test]
+    // Check that salted phrases round trip.
+    fn check_strip_salt() {
+        let mut rng = Csprng {};
+        for i in 0..=16 {
+            let mut data = vec![0u8; i];
+            let mut salt = [0u8; 4];
+            rng.fill_bytes(&mut data);
+            rng.fill_bytes(&mut salt);
+            let phrase = binary_to_phrase_with_salt(&data, &salt);
+            let (result, result_salt) = phrase_to_binary_strip_salt(&phrase).unwrap();
+            assert!(result == data);
+            assert!(result_salt == salt);
+        }
+        phrase_to_binary_strip_salt(&binary_to_phrase(&[1, 2, 3])).unwrap_err();
+        phrase_to_binary_strip_salt("abbey").unwrap_err();
+    }
+
+    #[test]
+    // Check that masking wraps the mask and is its own inverse.
+    fn check_mask() {
+        let phrase = binary_to_phrase(&[0, 0, 0, 0, 0]);
+        let masked = phrase_mask(&phrase, &[1, 2]).unwrap();
+        assert!(phrase_to_binary(&masked).unwrap() == [1, 2, 1, 2, 1]);
+        assert!(phrase_mask(&masked, &[1, 2]).unwrap() == phrase);
+        assert!(phrase_mask("", &[1]).unwrap() == "");
+        phrase_mask(&phrase, &[]).unwrap_err();
+        phrase_mask("abbey", &[1]).unwrap_err();
+    }
+
+    #[test]
+    // Check xoring a phrase against a key stream.
+    fn check_xor_key_stream() {
+        let phrase = binary_to_phrase(&[0u8; 5]);
+        let result = phrase_xor_key_stream(&phrase, 1u8..).unwrap();
+        assert!(phrase_to_binary(&result).unwrap() == [1, 2, 3, 4, 5]);
+        assert!(phrase_xor_key_stream(&result, 1u8..).unwrap() == phrase);
+        assert!(phrase_xor_key_stream("", std::iter::empty()).unwrap() == "");
+        phrase_xor_key_stream(&phrase, 1u8..5).unwrap_err();
+        phrase_xor_key_stream("abbey", 1u8..).unwrap_err();
+    }
+
+    #[test]
+    // Check left-padding data to a fixed length.
+    fn check_zero_padded() {
+        for i in 0..=8 {
+            let phrase = binary_to_phrase_zero_padded(&[1, 2], i + 2).unwrap();
+            let mut expected = vec![0u8; i];
+            expected.extend([1, 2]);
+            assert!(phrase_to_binary(&phrase).unwrap() == expected);
+        }
+        assert!(binary_to_phrase_zero_padded(&[], 0).unwrap() == "");
+        binary_to_phrase_zero_padded(&[1, 2], 1).unwrap_err();
+    }
+
+    #[test]
+    // Check that zero stripped phrases can be restored to their original length.
+    fn check_zero_stripped() {
+        let cases: [&[u8]; 5] = [&[], &[0, 0, 0], &[0, 0, 1, 0], &[1, 0, 0], &[0, 255, 255]];
+        for data in cases {
+            let phrase = binary_to_phrase_zero_stripped(data);
+            assert!(phrase_to_binary_zero_padded(&phrase, data.len()).unwrap() == data);
+        }
+        assert!(binary_to_phrase_zero_stripped(&[0, 0, 0]).is_empty());
+        phrase_to_binary_zero_padded(&binary_to_phrase(&[1, 2]), 1).unwrap_err();
+    }
+
+    #[test]
+    // Check xoring Phrase values together.
+    fn check_phrase_xor() {
+        let mut rng = Csprng {};
+        let mut a = vec![0u8; 9];
+        let mut b = vec![0u8; 9];
+        rng.fill_bytes(&mut a);
+        rng.fill_bytes(&mut b);
+        let a = Phrase::from(a);
+        let b = Phrase::from(b);
+        let shared = a.xor(&b).unwrap();
+        assert!(shared.xor(&b).unwrap() == a);
+        assert!(shared.xor(&a).unwrap() == b);
+        assert!(Phrase::default().xor(&Phrase::default()).unwrap().is_empty());
+        a.xor(&Phrase::default()).unwrap_err();
+    }
+
+    #[test]
+    // Check concatenating Phrase values.
+    fn check_phrase_concat() {
+        let a = Phrase::from(vec![1, 2, 3]);
+        let b = Phrase::from(vec![4, 5]);
+        assert!(a.concat(&b).as_bytes() == [1, 2, 3, 4, 5]);
+        assert!(b.concat(&a).as_bytes() == [4, 5, 1, 2, 3]);
+        assert!(a.concat(&Phrase::default()) == a);
+        let phrase = a.concat(&b).to_string();
+        assert!(phrase_to_binary(&phrase).unwrap() == [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    // Check taking byte ranges out of a Phrase.
+    fn check_phrase_slice() {
+        let phrase = Phrase::from(vec![1, 2, 3, 4]);
+        assert!(phrase.slice(0, 4).unwrap() == phrase);
+        assert!(phrase.slice(1, 2).unwrap().as_bytes() == [2]);
+        assert!(phrase.slice(4, 4).unwrap().is_empty());
+        phrase.slice(3, 2).unwrap_err();
+        phrase.slice(0, 5).unwrap_err();
+    }
+
+    #[test]
+    // Check reversing the bytes of a Phrase.
+    fn check_phrase_reverse() {
+        let phrase = Phrase::from(vec![1, 2, 3, 4]);
+        assert!(phrase.reverse().as_bytes() == [4, 3, 2, 1]);
+        assert!(phrase.reverse().reverse() == phrase);
+        assert!(Phrase::default().reverse().is_empty());
+    }
+
+    #[test]
+    // Check rotating the bytes of a Phrase in both directions.
+    fn check_phrase_rotate() {
+        let phrase = Phrase::from(vec![1, 2, 3, 4]);
+        assert!(phrase.rotate_left(0) == phrase);
+        assert!(phrase.rotate_left(4) == phrase);
+        assert!(phrase.rotate_left(5).as_bytes() == [2, 3, 4, 1]);
+        assert!(phrase.rotate_right(6).as_bytes() == [3, 4, 1, 2]);
+        for n in 0..10 {
+            assert!(phrase.rotate_left(n).rotate_right(n) == phrase);
+        }
+        assert!(Phrase::default().rotate_left(3).is_empty());
+        assert!(Phrase::default().rotate_right(3).is_empty());
+    }
+
+    #[test]
+    // Check the word limit on decoding.
+    fn check_max_words() {
+        let phrase = binary_to_phrase(&[7u8; 9]);
+        assert!(phrase_to_binary_max_words(&phrase, 5).unwrap() == [7u8; 9]);
+        assert!(phrase_to_binary_max_words(&phrase, 100).unwrap() == [7u8; 9]);
+        phrase_to_binary_max_words(&phrase, 4).unwrap_err();
+        assert!(phrase_to_binary_max_words("", 0).unwrap().is_empty());
+        phrase_to_binary_max_words("abbey", 1).unwrap_err();
+
+        // Words are counted the same way the decoder splits them.
+        phrase_to_binary_max_words("abbey0  ace64", 2).unwrap_err();
+        phrase_to_binary_max_words("abbey0  ace64", 3).unwrap_err();
+        phrase_to_binary_max_words(" abbey0", 2).unwrap_err();
+    }
+
+    #[test]
+    // Check the exact word count requirement on decoding.
+    fn check_exact_words() {
+        let phrase = binary_to_phrase(&[7u8; 9]);
+        assert!(phrase_to_binary_exact_words(&phrase, 5).unwrap() == [7u8; 9]);
+        phrase_to_binary_exact_words(&phrase, 4).unwrap_err();
+        phrase_to_binary_exact_words(&phrase, 6).unwrap_err();
+        assert!(phrase_to_binary_exact_words("", 0).unwrap().is_empty());
+        phrase_to_binary_exact_words("", 1).unwrap_err();
+
+        // Words are counted the same way the decoder splits them.
+        phrase_to_binary_exact_words("abbey0  ace64", 2).unwrap_err();
+        phrase_to_binary_exact_words("abbey0  ace64", 3).unwrap_err();
+        phrase_to_binary_exact_words("abbey0 ace64 ", 2).unwrap_err();
+    }
+
+    #[test]
+    // Check peeking at the first word of a phrase.
+    fn check_first_word() {
+        assert!(phrase_first_word("sugar21") == Some("sugar21"));
+        assert!(phrase_first_word("sugar21 toffee mob32") == Some("sugar21"));
+        assert!(phrase_first_word(" ").is_none());
+    }
+
+    #[test]
+    // Check peeking at the last word of a phrase.
+    fn check_last_word() {
+        assert!(phrase_last_word("sugar21") == Some("sugar21"));
+        assert!(phrase_last_word("sugar21 toffee mob32") == Some("mob32"));
+        assert!(phrase_last_word(" ").is_none());
+    }
+
+    #[test]
+    // Check detecting a single byte final word.
+    fn check_has_final_byte() {
+        for i in 1..=9 {
+            let phrase = binary_to_phrase(&vec![0u8; i]);
+            assert!(phrase_has_final_byte(&phrase) == Some(i % 2 == 1));
+        }
+        assert!(phrase_has_final_byte("abbey64 abbey0") == Some(false));
+        assert!(phrase_has_final_byte("").is_none());
+    }
+
+    #[test]
+    // Check detecting whether a phrase holds an even number of bytes.
+    fn check_is_even_bytes() {
+        for i in 1..=9 {
+            let phrase = binary_to_phrase(&vec![0u8; i]);
+            assert!(phrase_is_even_bytes(&phrase) == Some(i % 2 == 0));
+        }
+        assert!(phrase_is_even_bytes("").is_none());
+    }
+
+    #[test]
+    // Check reading individual bytes out of a phrase.
+    fn check_byte_at() {
+        let mut rng = Csprng {};
+        for i in 0..=16 {
+            let mut data = vec![0u8; i];
+            rng.fill_bytes(&mut data);
+            let phrase = binary_to_phrase(&data);
+            for (j, b) in data.iter().enumerate() {
+                assert!(phrase_byte_at(&phrase, j).unwrap() == *b);
+            }
+            phrase_byte_at(&phrase, i).unwrap_err();
+        }
+        phrase_byte_at("abbey0 toffee", 2).unwrap_err();
+
+        // Phrases the decoder rejects are rejected here too, wherever the problem is.
+        phrase_byte_at("ace64 abbey0", 0).unwrap_err();
+        phrase_byte_at("abbey0  ace64", 0).unwrap_err();
+        phrase_byte_at("abbey0 toffee", 0).unwrap_err();
+    }
+
+    #[test]
+    // Check that the split words and suffixes match the full phrase.
+    fn check_words_and_suffixes_only() {
+        let mut rng = Csprng {};
+        for i in 0..=16 {
+            let mut data = vec![0u8; i];
+            rng.fill_bytes(&mut data);
+            let words = binary_to_phrase_words_only(&data);
+            let suffixes = binary_to_phrase_suffixes_only(&data);
+            let tokens: Vec<String> = words.iter().zip(suffixes.iter()).map(|(w, s)| format!("{}{}", w, s)).collect();
+            assert!(tokens.join(" ") == binary_to_phrase(&data));
+        }
+    }
+
+    #[test]
+    // Check that split words and suffixes round trip.
+    fn check_from_words_and_suffixes() {
+        let mut rng = Csprng {};
+        for i in 0..=16 {
+            let mut data = vec![0u8; i];
+            rng.fill_bytes(&mut data);
+            let words = binary_to_phrase_words_only(&data);
+            let suffixes = binary_to_phrase_suffixes_only(&data);
+            let result = phrase_to_binary_from_words_and_suffixes(&words, &suffixes).unwrap();
+            assert!(result == data);
+        }
+        phrase_to_binary_from_words_and_suffixes(&["abbey"], &[0, 0]).unwrap_err();
+        phrase_to_binary_from_words_and_suffixes(&["abbey"], &[65]).unwrap_err();
+        phrase_to_binary_from_words_and_suffixes(&["abbey", "abbey"], &[64, 0]).unwrap_err();
+        phrase_to_binary_from_words_and_suffixes(&["zzz"], &[0]).unwrap_err();
+    }
+
+    #[test]
+    // Check counting zero bytes in a phrase.
+    fn check_count_zeroes() {
+        for i in 0..=9 {
+            assert!(phrase_count_zeroes(&binary_to_phrase(&vec![0u8; i])).unwrap() == i as u32);
+            assert!(phrase_count_zeroes(&binary_to_phrase(&vec![1u8; i])).unwrap() == 0);
+        }
+        phrase_count_zeroes("abbey").unwrap_err();
+    }
+
+    #[test]
+    // Check run-length encoding the bytes of a phrase.
+    fn check_run_length() {
+        assert!(phrase_run_length("").unwrap().is_empty());
+        assert!(phrase_run_length(&binary_to_phrase(&[0u8; 9])).unwrap() == [(0, 9)]);
+        assert!(phrase_run_length(&binary_to_phrase(&[1, 2, 2, 3])).unwrap() == [(1, 1), (2, 2), (3, 1)]);
+        phrase_run_length("abbey").unwrap_err();
+    }
+
+    #[test]
+    // Check the monotonicity score of decoded phrases.
+    fn check_monotone_score() {
+        assert!(phrase_monotone_score("").unwrap() == 0.0);
+        assert!(phrase_monotone_score(&binary_to_phrase(&[9])).unwrap() == 0.0);
+        assert!(phrase_monotone_score(&binary_to_phrase(&[0u8; 8])).unwrap() == 1.0);
+        assert!(phrase_monotone_score(&binary_to_phrase(&[5, 4, 3, 2, 1])).unwrap() == 0.0);
+
+        let mut rng = Csprng {};
+        let mut data = vec![0u8; 4096];
+        rng.fill_bytes(&mut data);
+        let score = phrase_monotone_score(&binary_to_phrase(&data)).unwrap();
+        assert!(score > 0.4 && score < 0.6);
+        phrase_monotone_score("abbey").unwrap_err();
+    }
+
+    #[test]
+    // Check hex encoding the bytes of a Phrase.
+    fn check_phrase_to_hex() {
+        assert!(Phrase::default().to_hex_string().is_empty());
+        let phrase: Phrase = (0u8..=255).collect();
+        let hex = phrase.to_hex_string();
+        assert!(hex.len() == 512);
+        assert!(hex.starts_with("000102"));
+        assert!(hex.ends_with("fdfeff"));
+    }
+
+    #[test]
+    // Check creating a Phrase from hex strings.
+    fn check_phrase_from_hex() {
+        let phrase: Phrase = (0u8..=255).collect();
+        assert!(Phrase::from_hex(&phrase.to_hex_string()).unwrap() == phrase);
+        assert!(Phrase::from_hex("").unwrap().is_empty());
+        assert!(Phrase::from_hex("0x").unwrap().is_empty());
+        assert!(Phrase::from_hex("ABCDEF").unwrap().as_bytes() == [0xab, 0xcd, 0xef]);
+        Phrase::from_hex("abc").unwrap_err();
+        Phrase::from_hex("0xzz").unwrap_err();
+        Phrase::from_hex("+f").unwrap_err();
+        Phrase::from_hex("éé").unwrap_err();
+    }
+
+    #[test]
+    #[cfg(feature = "base64")]
+    // Check creating a Phrase from Base64 strings.
+    fn check_phrase_from_base64() {
+        assert!(Phrase::from_base64("").unwrap().is_empty());
+        assert!(Phrase::from_base64("AAA=").unwrap().as_bytes() == [0, 0]);
+        assert!(Phrase::from_base64("/w==").unwrap().as_bytes() == [255]);
+        Phrase::from_base64("AAA").unwrap_err();
+        Phrase::from_base64("AA-=").unwrap_err();
+    }
+
+    #[test]
+    #[cfg(feature = "base64")]
+    // Check that Base64 encoding round trips.
+    fn check_phrase_to_base64() {
+        assert!(Phrase::from(vec![255]).to_base64() == "/w==");
+        for i in 0..=16 {
+            let phrase: Phrase = (0..i).collect();
+            assert!(Phrase::from_base64(&phrase.to_base64()).unwrap() == phrase);
+        }
+    }
+
+    #[test]
+    // Check splitting data into labeled phrases.
+    fn check_chunks_labeled() {
+        assert!(binary_to_phrase_chunks_labeled(&[], 4, "KEY").is_empty());
+        let chunks = binary_to_phrase_chunks_labeled(&[0u8; 10], 4, "KEY");
+        assert!(chunks.len() == 3);
+        assert!(chunks[0] == "KEY-1: abbey0 abbey0");
+        assert!(chunks[2] == "KEY-3: abbey0");
+    }
+
+    #[test]
+    // Check decoding labeled phrases, including numbering errors.
+    fn check_chunks_labeled_decode() {
+        let mut rng = Csprng {};
+        for i in 0..=32 {
+            let mut data = vec![0u8; i];
+            rng.fill_bytes(&mut data);
+            let chunks = binary_to_phrase_chunks_labeled(&data, 5, "KEY");
+            let mut chunks: Vec<&str> = chunks.iter().map(|c| c.as_str()).collect();
+            assert!(phrase_chunks_labeled_decode(&chunks, "KEY").unwrap() == data);
+            chunks.reverse();
+            assert!(phrase_chunks_labeled_decode(&chunks, "KEY").unwrap() == data);
+        }
+
+        phrase_chunks_labeled_decode(&["KEY-1: abbey0", "KEY-1: abbey0"], "KEY").unwrap_err();
+        phrase_chunks_labeled_decode(&["KEY-1: abbey0", "KEY-3: abbey0"], "KEY").unwrap_err();
+        phrase_chunks_labeled_decode(&["KEY-0: abbey0"], "KEY").unwrap_err();
+        phrase_chunks_labeled_decode(&["KEY-1: abbey0"], "SEED").unwrap_err();
+        phrase_chunks_labeled_decode(&["KEY-x: abbey0"], "KEY").unwrap_err();
+        phrase_chunks_labeled_decode(&["KEY-1 abbey0"], "KEY").unwrap_err();
+        phrase_chunks_labeled_decode(&["KEY-1: abbey"], "KEY").unwrap_err();
+    }
+
+    #[test]
+    // Check the lenient parsing of a final word without a suffix.
+    fn check_allow_no_suffix() {
+        for i in 0..=9 {
+            let data = vec![5u8; i];
+            let phrase = binary_to_phrase(&data);
+            assert!(parse_phrase_allow_no_suffix(&phrase).unwrap() == data);
+            let trimmed = phrase.strip_suffix("64").unwrap_or(&phrase);
+            assert!(parse_phrase_allow_no_suffix(trimmed).unwrap() == data);
+        }
+        parse_phrase_allow_no_suffix("yacht").unwrap_err();
+        parse_phrase_allow_no_suffix("abbey0 abbey abbey").unwrap_err();
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    // Check that the phrase string is cleared after decoding.
+    fn check_and_zero() {
+        let mut phrase = binary_to_phrase(&[1, 2, 3]);
+        assert!(phrase_to_binary_and_zero(&mut phrase).unwrap() == [1, 2, 3]);
+        assert!(phrase.is_empty());
+
+        let mut phrase = "abbey".to_string();
+        phrase_to_binary_and_zero(&mut phrase).unwrap_err();
+        assert!(phrase.is_empty());
+    }
+
+    #[test]
+    // Check that the alpha suffix notation matches the numerical notation word for word.
+    fn check_alpha_suffix_encode() {
+        assert!(binary_to_phrase_alpha_suffix(&[]).is_empty());
+        for i in 0..64u8 {
+            let alpha = binary_to_phrase_alpha_suffix(&[0, i]);
+            assert!(alpha == format!("abbey{}", ALPHA_SUFFIXES[i as usize] as char));
+        }
+        let mut rng = Csprng {};
+        let mut data = [0u8; 9];
+        rng.fill_bytes(&mut data);
+        let alpha = binary_to_phrase_alpha_suffix(&data);
+        let numeric = binary_to_phrase(&data);
+        assert!(alpha.split(' ').count() == numeric.split(' ').count());
+        assert!(alpha.ends_with(ALPHA_FINAL_SUFFIX));
+    }
+
+    #[test]
+    // Check that alpha suffix phrases round trip and that bad suffixes are rejected.
+    fn check_alpha_suffix_decode() {
+        let mut rng = Csprng {};
+        for i in 0..=32 {
+            let mut data = vec![0u8; i];
+            rng.fill_bytes(&mut data);
+            let phrase = binary_to_phrase_alpha_suffix(&data);
+            assert!(phrase_to_binary_alpha_suffix(&phrase).unwrap() == data);
+        }
+        phrase_to_binary_alpha_suffix("ab").unwrap_err();
+        phrase_to_binary_alpha_suffix("abbey-").unwrap_err();
+        phrase_to_binary_alpha_suffix("ab1A").unwrap_err();
+        phrase_to_binary_alpha_suffix("yacht=").unwrap_err();
+        phrase_to_binary_alpha_suffix("abbey0 ace64").unwrap_err();
+
+        // Whitespace is handled the same way as in phrase_to_binary.
+        let phrase = binary_to_phrase_alpha_suffix(&[0, 0, 5]);
+        assert!(phrase_to_binary_alpha_suffix(&phrase).unwrap() == [0, 0, 5]);
+        phrase_to_binary_alpha_suffix(&phrase.replace(' ', "  ")).unwrap_err();
+        phrase_to_binary_alpha_suffix(&format!(" {}", phrase)).unwrap_err();
+        phrase_to_binary_alpha_suffix(&format!("{} ", phrase)).unwrap_err();
+        assert!(phrase_to_binary_alpha_suffix("").unwrap().is_empty());
+    }
+
+    #[test]
+    // Check that words truncated to the unique prefix still decode.
+    fn check_unique_prefix_length() {
+        let prefix = dict_unique_prefix_length();
+        for word in DICTIONARY {
+            let truncated = format!("{}0", &word[..prefix]);
+            assert!(phrase_to_binary(&truncated).unwrap() == phrase_to_binary(&format!("{}0", word)).unwrap());
+        }
+    }
+
+    #[test]
+    // Check that words-only phrases decode with the suffix bits cleared.
+    fn check_words_only_decode() {
+        let mut rng = Csprng {};
+        for i in 0..=32 {
+            let mut data = vec![0u8; i];
+            rng.fill_bytes(&mut data);
+            let words = binary_to_phrase_words_only(&data).join(" ");
+            let result = phrase_to_binary_words_only(&words, i).unwrap();
+            for (j, b) in data.iter().enumerate() {
+                if j % 2 == 1 {
+                    assert!(result[j] == b & 0xc0);
+                } else {
+                    assert!(result[j] == *b);
+                }
+            }
+        }
+        phrase_to_binary_words_only("abbey abbey", 1).unwrap_err();
+        phrase_to_binary_words_only("abbey0", 2).unwrap_err();
+        phrase_to_binary_words_only("yacht", 1).unwrap_err();
+        assert!(phrase_to_binary_words_only("", 0).unwrap().is_empty());
+
+        // Whitespace is handled the same way as in phrase_to_binary.
+        assert!(phrase_to_binary_words_only("abbey ace", 3).unwrap() == [0, 0, 5]);
+        phrase_to_binary_words_only("abbey  ace", 3).unwrap_err();
+        phrase_to_binary_words_only("abbey ace ", 3).unwrap_err();
+        phrase_to_binary_words_only(" abbey ace", 3).unwrap_err();
+    }
+
+    #[test]
+    // Check parsing a phrase split into two halves.
+    fn check_from_parts() {
+        let data = [1u8, 2, 3, 4, 5];
+        let phrase = binary_to_phrase(&data);
+        let words: Vec<&str> = phrase.split(' ').collect();
+        for i in 0..=words.len() {
+            let left = words[..i].join(" ");
+            let right = words[i..].join(" ");
+            assert!(phrase_to_binary_from_parts(&left, &right).unwrap() == data);
+        }
+        phrase_to_binary_from_parts("abbey64", "abbey0").unwrap_err();
+        phrase_to_binary_from_parts("abbey0", "abbey").unwrap_err();
+    }
+
+    #[test]
+    // Check byte-level prefix detection between phrases.
+    fn check_is_prefix_of() {
+        let data = [1u8, 2, 3, 4, 5];
+        let long = binary_to_phrase(&data);
+        for i in 0..=data.len() {
+            assert!(phrase_is_prefix_of(&binary_to_phrase(&data[..i]), &long).unwrap());
+        }
+        assert!(!phrase_is_prefix_of(&binary_to_phrase(&[1, 3]), &long).unwrap());
+        phrase_is_prefix_of("abbey", &long).unwrap_err();
+        phrase_is_prefix_of(&long, "abbey").unwrap_err();
+    }
+
+    #[test]
+    // Check finding the common prefix of two phrases.
+    fn check_common_prefix() {
+        let a = binary_to_phrase(&[1, 2, 3, 4, 5]);
+        assert!(phrase_common_prefix(&a, &a).unwrap() == a);
+        assert!(phrase_common_prefix(&a, &binary_to_phrase(&[2])).unwrap().is_empty());
+        assert!(phrase_common_prefix(&a, "").unwrap().is_empty());
+        let b = binary_to_phrase(&[1, 2, 3]);
+        assert!(phrase_common_prefix(&a, &b).unwrap() == b);
+        assert!(phrase_common_prefix(&b, &a).unwrap() == b);
+        phrase_common_prefix(&a, "abbey").unwrap_err();
+    }
+
+    #[test]
+    // Check falling back to a default for invalid phrases.
+    fn check_or_default() {
+        assert!(phrase_to_binary_or_default(&binary_to_phrase(&[1, 2, 3]), &[]) == [1, 2, 3]);
+        assert!(phrase_to_binary_or_default("", &[1]).is_empty());
+        assert!(phrase_to_binary_or_default("sugar21 toffee mob32", &[1]) == [1]);
+    }
+
+    #[test]
+    #[cfg(feature = "log")]
+    // Check that decoding errors are turned into None.
+    fn check_log_errors() {
+        assert!(phrase_to_binary_log_errors(&binary_to_phrase(&[1, 2, 3])) == Some(vec![1, 2, 3]));
+        assert!(phrase_to_binary_log_errors("").unwrap().is_empty());
+        assert!(phrase_to_binary_log_errors("sugar21 toffee mob32").is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    // Check decoding inside a tracing span.
+    fn check_with_span() {
+        let (data, _span) = phrase_to_binary_with_span(&binary_to_phrase(&[1, 2, 3])).unwrap();
+        assert!(data == [1, 2, 3]);
+        phrase_to_binary_with_span("abbey").unwrap_err();
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    // Check that instrumented encoding matches binary_to_phrase.
+    fn check_instrumented() {
+        for i in 0..=9 {
+            let data = vec![3u8; i];
+            assert!(binary_to_phrase_instrumented(&data) == binary_to_phrase(&data));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "cache")]
+    // Check that the decode cache returns the same results as phrase_to_binary.
+    fn check_phrase_cache() {
+        set_phrase_cache_capacity(4);
+        for _ in 0..3 {
+            for i in 0..=9 {
+                let data = vec![i as u8; i];
+                assert!(phrase_to_binary_cache(&binary_to_phrase(&data)).unwrap() == data);
+            }
+        }
+        assert!(PHRASE_CACHE.with(|cache| cache.borrow().len()) == 4);
+        phrase_to_binary_cache("abbey").unwrap_err();
+        assert!(!PHRASE_CACHE.with(|cache| cache.borrow().contains("abbey")));
+
+        set_phrase_cache_capacity(0);
+        assert!(PHRASE_CACHE.with(|cache| cache.borrow().cap().get()) == 1);
+    }
+
+    #[test]
+    #[cfg(feature = "cache")]
+    // Check that clearing the decode cache empties it.
+    fn check_clear_phrase_cache() {
+        clear_phrase_cache();
+        phrase_to_binary_cache("abbey0").unwrap();
+        assert!(PHRASE_CACHE.with(|cache| cache.borrow().len()) == 1);
+        clear_phrase_cache();
+        assert!(PHRASE_CACHE.with(|cache| cache.borrow().is_empty()));
+        assert!(phrase_to_binary_cache("abbey0").unwrap() == [0, 0]);
+    }
+
+    #[test]
+    // Check that repeated words are rejected.
+    fn check_no_repetition() {
+        let data: Vec<u8> = (0..=255).collect();
+        assert!(binary_to_phrase_checked_no_repetition(&data).unwrap() == binary_to_phrase(&data));
+        assert!(binary_to_phrase_checked_no_repetition(&[]).unwrap().is_empty());
+        binary_to_phrase_checked_no_repetition(&[1, 2, 3, 4, 1, 2]).unwrap_err();
+
+        // The same word with a different suffix is not a repetition.
+        assert!(binary_to_phrase_checked_no_repetition(&[0, 0, 0, 1, 0]).unwrap() == "abbey0 abbey1 abbey64");
+    }
+
+    #[test]
+    // Check the byte histogram of decoded phrases.
+    fn check_byte_histogram() {
+        assert!(phrase_byte_histogram("").unwrap() == [0u32; 256]);
+        let data: Vec<u8> = (0..=255).chain(0..=255).collect();
+        assert!(phrase_byte_histogram(&binary_to_phrase(&data)).unwrap() == [2u32; 256]);
+        phrase_byte_histogram("abbey").unwrap_err();
+    }
+
+    #[test]
+    #[cfg(feature = "hmac")]
+    // Check verifying the hmac tag at the end of a phrase.
+    fn check_hmac_verify() {
+        let key = b"secret";
+        for i in 0..=9 {
+            let data = vec![4u8; i];
+            let tag = hmac_tag(&data, key);
+            let phrase = binary_to_phrase_with_hmac(&data, key);
+            assert!(phrase == binary_to_phrase_with_suffix_bytes(&data, &[tag]));
+            assert!(phrase_to_binary_with_hmac_verify(&phrase, key).unwrap() == data);
+            phrase_to_binary_with_hmac_verify(&phrase, b"other").unwrap_err();
+            let phrase = binary_to_phrase_with_suffix_bytes(&data, &[tag ^ 1]);
+            phrase_to_binary_with_hmac_verify(&phrase, key).unwrap_err();
+        }
+        phrase_to_binary_with_hmac_verify("", key).unwrap_err();
+    }
+
+    #[test]
+    // Check that usize values round trip through phrases.
+    fn check_usize() {
+        for addr in [0, 1, 255, 256, usize::MAX / 3, usize::MAX] {
+            let phrase = phrase_from_usize(addr);
+            assert!(phrase_to_binary(&phrase).unwrap().len() == std::mem::size_of::<usize>());
+            assert!(phrase_to_usize(&phrase).unwrap() == addr);
+        }
+        phrase_to_usize("").unwrap_err();
+        phrase_to_usize(&binary_to_phrase(&[0u8; 9])).unwrap_err();
+    }
+
+    #[test]
+    // Check that timestamps round trip through phrases.
+    fn check_timestamp() {
+        use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+        let now = SystemTime::now();
+        let phrase = phrase_encode_timestamp(now);
+        let decoded = phrase_decode_timestamp(&phrase).unwrap();
+        assert!(now.duration_since(decoded).unwrap() < Duration::from_secs(1));
+
+        let before_epoch = UNIX_EPOCH - Duration::from_secs(10);
+        assert!(phrase_decode_timestamp(&phrase_encode_timestamp(before_epoch)).unwrap() == UNIX_EPOCH);
+
+        phrase_decode_timestamp(&binary_to_phrase(&[0u8; 7])).unwrap_err();
+        phrase_decode_timestamp(&binary_to_phrase(&[255u8; 8])).unwrap_err();
+    }
+
+    #[test]
+    // Check that UUIDs round trip through phrases.
+    fn check_uuid() {
+        let mut rng = Csprng {};
+        for _ in 0..8 {
+            let mut uuid = [0u8; 16];
+            rng.fill_bytes(&mut uuid);
+            let phrase = phrase_encode_uuid(&uuid);
+            assert!(phrase_to_binary_exact_words(&phrase, 8).unwrap() == uuid);
+            assert!(phrase_decode_uuid(&phrase).unwrap() == uuid);
+        }
+        phrase_decode_uuid(&binary_to_phrase(&[0u8; 15])).unwrap_err();
+        phrase_decode_uuid(&binary_to_phrase(&[0u8; 17])).unwrap_err();
+    }
+
+    #[test]
+    // Check the edit distance helper and fuzzy decoding.
+    fn check_fuzzy_match() {
+        assert!(edit_distance("", "") == 0);
+        assert!(edit_distance("abc", "") == 3);
+        assert!(edit_distance("kitten", "sitting") == 3);
+        assert!(edit_distance("sugar", "sugar") == 0);
+
+        let data = [1u8, 2, 3, 4, 5];
+        let phrase = binary_to_phrase(&data);
+        let (result, substitutions) = phrase_to_binary_fuzzy_match(&phrase).unwrap();
+        assert!(result == data);
+        assert!(substitutions.is_empty());
+
+        let (result, substitutions) = phrase_to_binary_fuzzy_match("sugar21 tofee5 mob32").unwrap();
+        assert!(result == phrase_to_binary("sugar21 toffee5 mob32").unwrap());
+        assert!(substitutions.is_empty());
+        let (result, substitutions) = phrase_to_binary_fuzzy_match("sugar21 taffee5 mob32").unwrap();
+        assert!(result == phrase_to_binary("sugar21 toffee5 mob32").unwrap());
+        assert!(substitutions == ["taffee → toffee"]);
+        let (_, substitutions) = phrase_to_binary_fuzzy_match("ééé0 mob32").unwrap();
+        assert!(substitutions.len() == 1);
+
+        // Errors that are not caused by the word itself are still reported.
+        phrase_to_binary_fuzzy_match("sugar21 taffee mob32").unwrap_err();
+
+        // Tokens without enough letters to match against are rejected rather than guessed.
+        phrase_to_binary_fuzzy_match("21 mob32").unwrap_err();
+        phrase_to_binary_fuzzy_match("sugar21 64").unwrap_err();
+        phrase_to_binary_fuzzy_match("su21 mob32").unwrap_err();
+        let (_, substitutions) = phrase_to_binary_fuzzy_match("zzz21 mob32").unwrap();
+        assert!(substitutions.len() == 1);
+
+        // Whitespace is handled the same way as in phrase_to_binary.
+        phrase_to_binary_fuzzy_match("abbey0  ace64").unwrap_err();
+        phrase_to_binary_fuzzy_match(" abbey0 ace64").unwrap_err();
+        phrase_to_binary_fuzzy_match("abbey0 ace64 ").unwrap_err();
+        phrase_to_binary_fuzzy_match("abbey0\tace64").unwrap_err();
+        let (result, substitutions) = phrase_to_binary_fuzzy_match("").unwrap();
+        assert!(result.is_empty());
+        assert!(substitutions.is_empty());
+    }
+
+    #[test]
+    // Check that corrections are only suggested for a single bad word.
+    fn check_suggest_correction() {
+        let phrase = binary_to_phrase(&[9, 8, 7, 6, 5]);
+        assert!(phrase_suggest_correction(&phrase).unwrap().is_none());
+        assert!(phrase_suggest_correction("sugar21 taffee5 mob32").unwrap() == Some("sugar21 toffee5 mob32".to_string()));
+        assert!(phrase_suggest_correction("zzz21 taffee5 mob32").unwrap().is_none());
+        phrase_suggest_correction("sugar21 toffee mob32").unwrap_err();
+        phrase_suggest_correction("sugar21 taffee mob32").unwrap_err();
+
+        // Tokens without enough letters to match against are rejected rather than guessed.
+        phrase_suggest_correction("21 mob32").unwrap_err();
+        phrase_suggest_correction("sugar21 toffee5 32").unwrap_err();
+        phrase_suggest_correction("su21 mob32").unwrap_err();
+
+        // More than one invalid word gives no suggestion, even if one of them is too short.
+        assert!(phrase_suggest_correction("zzz21 21").unwrap().is_none());
+        assert!(phrase_suggest_correction("21 zzz21").unwrap().is_none());
+
+        // Whitespace is handled the same way as in phrase_to_binary.
+        phrase_suggest_correction("sugar21  mob32").unwrap_err();
+        phrase_suggest_correction("sugar21 mob32 ").unwrap_err();
+        assert!(phrase_suggest_correction("").unwrap().is_none());
+    }
+
+    #[test]
+    // Check that non-ascii words produce an error rather than a panic.
+    fn check_non_ascii_words() {
+        phrase_to_binary("aaé0 ace64").unwrap_err();
+        phrase_to_binary("abbey0 aaé64").unwrap_err();
+        phrase_to_binary("ééé0").unwrap_err();
+        phrase_to_binary("abbey٣").unwrap_err();
+    }
+
+    #[test]
+    // Check the alignment validation when decoding.
+    fn check_padding_validation() {
+        let mut rng = Csprng {};
+        let mut data = [0u8; 32];
+        rng.fill_bytes(&mut data);
+        let phrase = binary_to_phrase(&data);
+        assert!(phrase_to_binary_with_padding_validation(&phrase, 1).unwrap() == data);
+        assert!(phrase_to_binary_with_padding_validation(&phrase, 16).unwrap() == data);
+        assert!(phrase_to_binary_with_padding_validation(&phrase, 32).unwrap() == data);
+        assert!(phrase_to_binary_with_padding_validation("", 16).unwrap().is_empty());
+
+        let err = phrase_to_binary_with_padding_validation(&phrase, 5).unwrap_err();
+        assert!(err.to_string().contains("multiple of 5, got 32"));
+        phrase_to_binary_with_padding_validation(&phrase, 0).unwrap_err();
+        phrase_to_binary_with_padding_validation("abbey", 1).unwrap_err();
+    }
+
+    #[test]
+    // Check that aligned encoding pads to the requested boundary.
+    fn check_aligned_encoding() {
+        let mut rng = Csprng {};
+        for len in 0..40 {
+            let mut data = vec![0u8; len];
+            rng.fill_bytes(&mut data);
+            let phrase = binary_to_phrase_aligned(&data, 16);
+            let decoded = phrase_to_binary_with_padding_validation(&phrase, 16).unwrap();
+            assert!(decoded.len() == len.div_ceil(16) * 16);
+            assert!(decoded[..len] == data[..]);
+            assert!(decoded[len..].iter().all(|b| *b == 0));
+        }
+        assert!(binary_to_phrase_aligned(&[1, 2, 3], 0) == binary_to_phrase(&[1, 2, 3]));
+        assert!(binary_to_phrase_aligned(&[1, 2, 3], 1) == binary_to_phrase(&[1, 2, 3]));
+    }
+
+    #[test]
+    // Pin the encoding against known vectors so that the format cannot change by accident.
+    fn check_known_vectors() {
+        let vectors: [(&[u8], &str); 8] = [
+            (&[], ""),
+            (&[0, 0], "abbey0"),
+            (&[0, 63], "abbey63"),
+            (&[0, 64], "able0"),
+            (&[1, 0], "abyss0"),
+            (&[255, 255], "yank63"),
+            (&[5], "ace64"),
+            (&[0, 0, 255, 255, 5], "abbey0 yank63 ace64"),
+        ];
+        for (data, phrase) in vectors {
+            assert!(binary_to_phrase(data) == phrase);
+            assert!(phrase_to_binary(phrase).unwrap() == data);
+        }
     }
 }